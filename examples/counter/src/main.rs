@@ -9,32 +9,35 @@ use snowscape::{dynamic, stateful, stateless};
 pub fn previews(app: snowscape::App) -> snowscape::App {
     app.title("Counter Previews")
         .with_tests_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests"))
-        .preview(
+        .preview_fn(|| {
             dynamic::stateless("Label", dynamic::text("Content", "Editable"), |content| {
                 label(content)
             })
-            .description("A label with editable content"),
-        )
-        .preview(stateless("Increment", add_button).group("Button"))
-        .preview(stateless("Decrement", minus_button).group("Button"))
-        .preview(
+            .description("A label with editable content")
+        })
+        .preview_fn(|| stateless("Increment", add_button).group("Button"))
+        .preview_fn(|| stateless("Decrement", minus_button).group("Button"))
+        .preview_fn(|| {
             stateful("Counter", App::default, App::update, App::view)
                 .description("A counter that increments when the button is pressed")
-                .tags(["counter", "stateful"]),
-        )
-        .preview(dynamic::stateful(
-            "Adjustable counter",
-            (
-                dynamic::text("Increment label", "Increment"),
-                dynamic::text("Decrement label", "Decrement"),
-            ),
-            App::default,
-            App::update,
-            |state, params| {
-                let (inc_label, dec_label) = params;
-                adjustable_counter(state.count, inc_label, dec_label)
-            },
-        ))
+                .tags(["counter", "stateful"])
+                .variant("Starting at ten", || App::new(10))
+        })
+        .preview_fn(|| {
+            dynamic::stateful(
+                "Adjustable counter",
+                (
+                    dynamic::text("Increment label", "Increment"),
+                    dynamic::text("Decrement label", "Decrement"),
+                ),
+                App::default,
+                App::update,
+                |state, params| {
+                    let (inc_label, dec_label) = params;
+                    adjustable_counter(state.count, inc_label, dec_label)
+                },
+            )
+        })
 }
 
 pub fn main() -> iced::Result {
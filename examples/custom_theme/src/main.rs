@@ -3,6 +3,7 @@ pub mod theme;
 use iced::{
     Element,
     Length::{Fill, Fit},
+    theme::Base,
     widget::{Button, Text, column, container, row, space, text::IntoFragment, themer},
 };
 use snowscape::dynamic;
@@ -12,22 +13,31 @@ use crate::theme::{ContainerVariant, CustomTheme, TextVariant};
 /// Previews various components used within Snowscape.
 fn main() -> iced::Result {
     snowscape::run(|app| {
-        app.title("Custom Themed Previews").preview(
-            dynamic::stateless(
-                "Product Card",
-                (
-                    dynamic::text("Title", "Awesome Gadget"),
-                    dynamic::text(
-                        "Description",
-                        "This gadget is awesome because it has many features.",
+        app.title("Custom Themed Previews")
+            // Center every preview on the custom theme's background.
+            .decorator(|content| {
+                let background = CustomTheme::default().base().background_color;
+                container(content)
+                    .center(Fill)
+                    .style(move |_theme| container::background(background))
+                    .into()
+            })
+            .preview_fn(|| {
+                dynamic::stateless(
+                    "Product Card",
+                    (
+                        dynamic::text("Title", "Awesome Gadget"),
+                        dynamic::text(
+                            "Description",
+                            "This gadget is awesome because it has many features.",
+                        ),
+                        dynamic::number("Price", 50),
                     ),
-                    dynamic::number("Price", 50),
-                ),
-                |(title, description, price)| wrapper(product_card(title, description, *price)),
-            )
-            .tags(["Product", "Card", "Price"])
-            .description("A card displaying a product, the price, and a buy button."),
-        )
+                    |(title, description, price)| themed(product_card(title, description, *price)),
+                )
+                .tags(["Product", "Card", "Price"])
+                .description("A card displaying a product, the price, and a buy button.")
+            })
     })
 }
 
@@ -56,16 +66,9 @@ fn card<'a>(
         .padding(16)
 }
 
-/// A wrapper that converts content into a themed container.
-/// This is used to apply your custom theme to all preview content.
-fn wrapper<'a>(content: impl Into<Element<'a, Message, CustomTheme>>) -> Element<'a, Message> {
-    themer(
-        Some(CustomTheme::default()),
-        container(content)
-            .class(ContainerVariant::Background)
-            .center(Fill),
-    )
-    .into()
+/// Applies the custom theme to the content of a preview.
+fn themed<'a>(content: impl Into<Element<'a, Message, CustomTheme>>) -> Element<'a, Message> {
+    themer(Some(CustomTheme::default()), content).into()
 }
 
 /// An example product card component that you might have in your app.
@@ -13,6 +13,7 @@ pub fn adjustable_view(
     alignment: Alignment,
     padding: f32,
     color: Color,
+    opacity: f32,
 ) -> Element<'_, ()> {
     let align = match alignment {
         Alignment::Left => iced::Alignment::Start,
@@ -31,7 +32,7 @@ pub fn adjustable_view(
     // Color swatch
     let color_swatch =
         container(space().width(20).height(20)).style(move |_theme| container::Style {
-            background: Some(color.into()),
+            background: Some(color.scale_alpha(opacity).into()),
             border: iced::border::rounded(4),
             ..Default::default()
         });
@@ -47,6 +48,7 @@ pub fn adjustable_view(
             }),
             text!("Alignment: {}", alignment).size(14),
             text!("Padding: {:.0}px", padding).size(14),
+            text!("Opacity: {:.0}%", opacity * 100.0).size(14),
             row![
                 text("Color: ").size(14),
                 color_swatch,
@@ -64,11 +66,13 @@ pub fn adjustable_view(
 }
 
 /// Alignment options for the layout.
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, snowscape::ParamEnum)]
 pub enum Alignment {
+    /// Aligns the content to the start of the view.
     Left,
     #[default]
     Center,
+    /// Aligns the content to the end of the view.
     Right,
 }
 
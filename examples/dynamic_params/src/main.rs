@@ -1,30 +1,78 @@
 use dynamic_params::{Alignment, adjustable_view};
 use iced::Color;
 use snowscape::dynamic;
+use snowscape::preview::Preview;
 
-pub fn main() -> iced::Result {
-    snowscape::run(|app| {
-        app.title("Dynamic Parameters")
-            .preview(
-                dynamic::stateless(
-                    "All dynamic params",
-                    (
-                        dynamic::text("Label", "The meaning of life"),
-                        dynamic::number("The magic number", 42),
-                        dynamic::boolean("A toggle", true),
-                        dynamic::select(
-                            "Alignment",
-                            &[Alignment::Left, Alignment::Center, Alignment::Right],
-                            Alignment::Center,
-                        ),
-                        dynamic::slider("Padding", 0.0..=64.0, 16.0),
-                        dynamic::color("Background", Color::from_rgb(0.0, 0.78, 1.0)),
-                    ),
-                    |(label, number, toggle, alignment, padding, color)| {
-                        adjustable_view(label, *number, *toggle, *alignment, *padding, *color)
-                    },
-                )
-                .description("Demonstrates all dynamic parameter types: text, number, boolean, select, slider, and color"),
+/// Demonstrates all dynamic parameter types: text, number, float, boolean, select, slider, and color
+#[snowscape::preview(tags = ["dynamic"])]
+fn all_dynamic_params() -> impl Preview {
+    dynamic::stateless(
+        "All dynamic params",
+        (
+            dynamic::text("Label", "The meaning of life"),
+            dynamic::number("The magic number", 42),
+            dynamic::boolean("A toggle", true),
+            dynamic::enumeration("Alignment", Alignment::Center).segmented(),
+            dynamic::slider("Padding", 0.0..=64.0, 16.0),
+            dynamic::color("Background", Color::from_rgb(0.0, 0.78, 1.0)),
+            dynamic::float("Opacity", 1.0f32)
+                .range(0.0..=1.0)
+                .step(0.05),
+        ),
+        |(label, number, toggle, alignment, padding, color, opacity)| {
+            adjustable_view(
+                label, *number, *toggle, *alignment, *padding, *color, *opacity,
             )
-    })
+        },
+    )
+}
+
+/// The same parameters as a struct, which the preview receives as is.
+#[derive(Clone, snowscape::Params)]
+struct Adjustable {
+    /// The text shown at the top of the view.
+    label: String,
+    #[param(name = "The magic number")]
+    number: i32,
+    toggle: bool,
+    #[param(select, segmented)]
+    alignment: Alignment,
+    /// The space around the content.
+    #[param(slider = 0.0..=64.0)]
+    padding: f32,
+    background: Color,
+    #[param(range = 0.0..=1.0, step = 0.05)]
+    opacity: f32,
+}
+
+/// Demonstrates deriving the parameters of a preview from a struct
+#[snowscape::preview(tags = ["dynamic"])]
+fn derived_params() -> impl Preview {
+    dynamic::stateless(
+        "Derived params",
+        dynamic::params(Adjustable {
+            label: String::from("The meaning of life"),
+            number: 42,
+            toggle: true,
+            alignment: Alignment::Center,
+            padding: 16.0,
+            background: Color::from_rgb(0.0, 0.78, 1.0),
+            opacity: 1.0,
+        }),
+        |params| {
+            adjustable_view(
+                &params.label,
+                params.number,
+                params.toggle,
+                params.alignment,
+                params.padding,
+                params.background,
+                params.opacity,
+            )
+        },
+    )
+}
+
+pub fn main() -> iced::Result {
+    snowscape::run(|app| app.title("Dynamic Parameters").with_registered())
 }
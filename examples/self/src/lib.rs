@@ -1,9 +1,11 @@
+use std::collections::HashSet;
 use std::time::Duration;
 
 use iced::Alignment::Center;
 use iced::Length::{Fill, Fit};
-use iced::widget::{button, column, container, pick_list, row, space, text};
-use iced::{Color, Element};
+use iced::widget::{button, column, container, markdown, pick_list, row, space, text, themer};
+use iced::{Color, Element, Theme};
+use snowscape::metadata::{Link, Status};
 use snowscape::preview::{Performance, Preview};
 use snowscape::preview::{dynamic, performance::Indicator, stateful, stateless, stateless_with};
 use snowscape::test::discovery::TestInfo;
@@ -13,16 +15,16 @@ use snowscape::{App, ConfigTab, Metadata, test, widget};
 pub fn previews(app: App) -> App {
     app.title("Snowscape Previews")
         .with_tests_dir(format!("{}/tests", env!("CARGO_MANIFEST_DIR")))
-        .preview(config_tabs())
-        .preview(preview_list())
-        .preview(about_pane())
-        .preview(parameter_pane())
-        .preview(message_pane())
-        .preview(performance_pane())
-        .preview(test_pane())
-        .preview(dialog_preview())
-        .preview(dialog_preview_no_animation())
-        .preview(app_preview())
+        .preview_fn(config_tabs)
+        .preview_fn(preview_list)
+        .preview_fn(about_pane)
+        .preview_fn(parameter_pane)
+        .preview_fn(message_pane)
+        .preview_fn(performance_pane)
+        .preview_fn(test_pane)
+        .preview_fn(dialog_preview)
+        .preview_fn(dialog_preview_no_animation)
+        .preview_fn(app_preview)
 }
 
 /// Builds the Snowscape app as a standalone [`iced::Program`] for use in
@@ -78,35 +80,70 @@ fn config_tabs() -> impl Preview {
 fn preview_list() -> impl Preview {
     stateless_with(
         "Preview List",
-        vec![
-            stateless("Item 1", || -> Element<'static, ()> { space().into() }).into(),
-            stateless("Item 2", || -> Element<'static, ()> { space().into() }).into(),
-            stateless("Item 3", || -> Element<'static, ()> { space().into() }).into(),
-        ],
-        |items| {
-            container(widget::preview_list(items.iter().enumerate(), Some(1)))
-                .width(Fit.max(200))
-                .into()
+        (
+            vec![
+                stateless("Item 1", || -> Element<'static, ()> { space().into() })
+                    .group("Forms/Inputs")
+                    .into(),
+                stateless("Item 2", || -> Element<'static, ()> { space().into() })
+                    .group("Forms")
+                    .into(),
+                stateless("Item 3", || -> Element<'static, ()> { space().into() })
+                    .group("Layout")
+                    .into(),
+                stateless("Item 4", || -> Element<'static, ()> { space().into() }).into(),
+            ],
+            HashSet::from([String::from("Layout")]),
+        ),
+        |(items, collapsed)| {
+            container(widget::preview_list(
+                items.iter().enumerate(),
+                Some(1),
+                collapsed,
+                "",
+            ))
+            .width(Fit.max(200))
+            .into()
         },
     )
     .description(
         "Shows the user a list of previews that appears in the app's sidebar so the \
-        user can choose which preview to view. The selected preview is highlighted.",
+        user can choose which preview to view. Previews are nested in collapsible \
+        groups by their group path, and the selected preview is highlighted.",
     )
 }
 
 fn about_pane() -> impl Preview {
-    stateless_with(
+    let description = "This is a longer description about the component being previewed.\n\n\
+        - Descriptions are rendered as **Markdown**\n\
+        - [Links](https://iced.rs) open in the browser";
+
+    dynamic::stateless_with(
         "About Pane",
-        Metadata {
-            label: String::from("A label about a component"),
-            description: Some(String::from(
-                "This is a longer description about the component being previewed.",
-            )),
-            group: Some(String::from("Group Name")),
-            tags: vec![String::from("tag1"), String::from("tag2")],
+        (
+            Metadata {
+                label: String::from("A label about a component"),
+                description: Some(String::from(description)),
+                group: Some(String::from("Group Name")),
+                tags: vec![String::from("tag1"), String::from("tag2")],
+                variant: None,
+                status: Some(Status::Experimental),
+                owners: vec![String::from("Design Systems")],
+                links: vec![Link::new("Design", "https://iced.rs")],
+                fields: vec![(String::from("Since"), String::from("0.1"))],
+                location: None,
+            },
+            markdown::parse(description).collect::<Vec<_>>(),
+        ),
+        dynamic::theme("Theme", Theme::Light),
+        |(metadata, description), theme| {
+            // Render the whole pane with the theme its markdown is styled for.
+            themer(
+                Some(theme.clone()),
+                widget::config_pane::about_pane::about_pane(metadata, description, theme),
+            )
+            .into()
         },
-        widget::config_pane::about_pane::about_pane,
     )
     .description(
         "Shows metadata information that has been associated with the preview. These \
@@ -119,6 +156,7 @@ fn about_pane() -> impl Preview {
 fn parameter_pane() -> impl Preview {
     struct App {
         params: Vec<dynamic::Param>,
+        draft: Option<widget::config_pane::parameter_pane::NumberDraft>,
     }
 
     impl App {
@@ -134,18 +172,24 @@ fn parameter_pane() -> impl Preview {
                         dynamic::Value::Color(Color::from_rgba8(0, 178, 255, 1.0)),
                     ),
                 ],
+                draft: None,
             }
         }
 
         fn view(&self) -> Element<'_, snowscape::Message> {
-            widget::config_pane::parameter_pane::parameter_pane(&self.params)
+            widget::config_pane::parameter_pane::parameter_pane(&self.params, self.draft.as_ref())
         }
 
         fn update(&mut self, message: snowscape::Message) {
-            if let snowscape::Message::ChangeParam(index, value) = message
-                && let Some(param) = self.params.get_mut(index)
-            {
-                param.value = value;
+            match message {
+                snowscape::Message::ChangeParam(index, value) => {
+                    self.draft = None;
+                    if let Some(param) = self.params.get_mut(index) {
+                        param.value = value;
+                    }
+                }
+                snowscape::Message::DraftNumber(draft) => self.draft = Some(draft),
+                _ => {}
             }
         }
     }
@@ -423,15 +467,15 @@ fn app_preview() -> impl Preview {
         || {
             snowscape::App::default()
                 .title("Nested App")
-                .preview(config_tabs())
-                .preview(preview_list())
-                .preview(about_pane())
-                .preview(parameter_pane())
-                .preview(message_pane())
-                .preview(performance_pane())
-                .preview(test_pane())
-                .preview(dialog_preview())
-                .preview(dialog_preview_no_animation())
+                .preview_fn(config_tabs)
+                .preview_fn(preview_list)
+                .preview_fn(about_pane)
+                .preview_fn(parameter_pane)
+                .preview_fn(message_pane)
+                .preview_fn(performance_pane)
+                .preview_fn(test_pane)
+                .preview_fn(dialog_preview)
+                .preview_fn(dialog_preview_no_animation)
         },
         snowscape::App::internal_update,
         snowscape::App::internal_view,
@@ -0,0 +1,619 @@
+//! Procedural macros for Snowscape, which are re-exported by the `snowscape` crate.
+
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, Expr, ExprLit, Fields, Ident, ItemFn, Lit, LitStr, Meta, Token,
+    Visibility, parse_macro_input,
+};
+
+/// Registers a function that returns a preview, so that it's added to the app by
+/// `App::with_registered` instead of being chained into `snowscape::run` manually.
+///
+/// The function can't take any arguments. Its doc comment becomes the Markdown description
+/// of the preview unless the preview already has one.
+///
+/// ```ignore
+/// /// A button that increments the counter.
+/// #[snowscape::preview(group = "Buttons", tags = ["counter"])]
+/// fn increment() -> impl snowscape::preview::Preview {
+///     snowscape::stateless("Increment", add_button)
+/// }
+///
+/// pub fn main() -> iced::Result {
+///     snowscape::run(|app| app.with_registered())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn preview(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut arguments = Arguments::default();
+    let parser = syn::meta::parser(|meta| arguments.parse(meta));
+    parse_macro_input!(args with parser);
+
+    let function = parse_macro_input!(item as ItemFn);
+    match expand(arguments, function) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// The arguments of the `preview` attribute.
+#[derive(Default)]
+struct Arguments {
+    /// The group to place the preview in, e.g. `group = "Forms/Inputs"`.
+    group: Option<LitStr>,
+    /// Tags to add to the preview, e.g. `tags = ["form", "input"]`.
+    tags: Vec<LitStr>,
+}
+
+impl Arguments {
+    /// Parses a single `name = value` argument of the attribute.
+    fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("group") {
+            self.group = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("tags") {
+            let value = meta.value()?;
+            let content;
+            syn::bracketed!(content in value);
+            let tags = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+            self.tags.extend(tags);
+            Ok(())
+        } else {
+            Err(meta.error("expected `group` or `tags`"))
+        }
+    }
+}
+
+/// Keeps the `function` as is and submits a registration that adds its preview to the app.
+fn expand(arguments: Arguments, function: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let signature = &function.sig;
+    if let Some(input) = signature.inputs.first() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "preview functions can't take any arguments",
+        ));
+    }
+    if let Some(asyncness) = signature.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "preview functions can't be async",
+        ));
+    }
+    if !signature.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &signature.generics,
+            "preview functions can't be generic",
+        ));
+    }
+
+    let name = &signature.ident;
+    let group = match &arguments.group {
+        Some(group) => quote! { ::core::option::Option::Some(#group) },
+        None => quote! { ::core::option::Option::None },
+    };
+    let tags = &arguments.tags;
+
+    // The Code tab shows the function from its visibility or `fn` keyword onwards.
+    let start = match &function.vis {
+        Visibility::Inherited => signature.fn_token.span,
+        visibility => visibility.span(),
+    };
+    let location = quote_spanned! {start=>
+        ::snowscape::metadata::Location::new(file!(), line!(), column!())
+    };
+    let description = match description(&function.attrs) {
+        Some(description) => quote! { ::core::option::Option::Some(#description) },
+        None => quote! { ::core::option::Option::None },
+    };
+
+    Ok(quote! {
+        #function
+
+        ::snowscape::registry::inventory::submit! {
+            ::snowscape::registry::Registration::new(module_path!(), line!(), {
+                fn register(app: ::snowscape::App) -> ::snowscape::App {
+                    app.preview_fn(|| {
+                        ::snowscape::registry::configure(
+                            #name(),
+                            #group,
+                            &[#(#tags),*],
+                            #description,
+                            #location,
+                        )
+                    })
+                }
+                register
+            })
+        }
+    })
+}
+
+/// Implements `snowscape::dynamic::Params` for a struct with named fields, so that each
+/// field becomes an adjustable parameter and previews receive the struct itself.
+///
+/// Fields are named after their identifier, e.g. `font_size` becomes "Font size", and their
+/// doc comments are shown as tooltips. The `param` attribute changes the kind of parameter:
+///
+/// ```ignore
+/// #[derive(Clone, snowscape::Params)]
+/// struct Card {
+///     /// The title at the top of the card.
+///     title: String,
+///     #[param(slider = 0.0..=1.0)]
+///     opacity: f32,
+///     #[param(range = 0.0..=8.0, step = 0.5, unit = "px")]
+///     border_width: f32,
+///     #[param(name = "Size", select = [Size::Small, Size::Large])]
+///     size: Size,
+/// }
+///
+/// dynamic::stateless("Card", dynamic::params(card), |card| view(card))
+/// ```
+#[proc_macro_derive(Params, attributes(param))]
+pub fn derive_params(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match expand_params(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// The kind of parameter that a field of a struct deriving `Params` becomes.
+enum Kind {
+    /// The parameter of the field's `DefaultParam` implementation.
+    Default,
+    /// A slider within the given range, e.g. `slider = 0.0..=1.0`.
+    Slider(Expr),
+    /// A selection from the given options, or the variants of a `ParamEnum` if there are none.
+    Select(Option<Expr>),
+    /// A float with an optional range, step, and unit, e.g. `range = 0.0..=1.0, step = 0.1`.
+    Float {
+        range: Option<Expr>,
+        step: Option<Expr>,
+        unit: Option<LitStr>,
+    },
+}
+
+/// The `param` attribute of a field, along with its name and description.
+struct Field<'a> {
+    ident: &'a Ident,
+    ty: &'a syn::Type,
+    name: String,
+    description: Option<String>,
+    kind: Kind,
+    /// Whether a selection is shown as segmented buttons, i.e. `select, segmented`.
+    segmented: bool,
+}
+
+impl<'a> Field<'a> {
+    /// Parses the `param` attributes and the doc comment of the `field`.
+    fn new(field: &'a syn::Field) -> syn::Result<Self> {
+        let ident = field.ident.as_ref().expect("fields must be named");
+        let mut name = None;
+        let mut kind = Kind::Default;
+        let mut segmented = None;
+
+        for attribute in field.attrs.iter().filter(|a| a.path().is_ident("param")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    name = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("slider") {
+                    if !matches!(kind, Kind::Default) {
+                        return Err(meta.error("`slider` can't be combined with other kinds"));
+                    }
+                    kind = Kind::Slider(meta.value()?.parse()?);
+                } else if meta.path.is_ident("select") {
+                    if !matches!(kind, Kind::Default) {
+                        return Err(meta.error("`select` can't be combined with other kinds"));
+                    }
+                    let options = if meta.input.peek(Token![=]) {
+                        Some(meta.value()?.parse()?)
+                    } else {
+                        None
+                    };
+                    kind = Kind::Select(options);
+                } else if meta.path.is_ident("segmented") {
+                    segmented = Some(meta.path.clone());
+                } else if meta.path.is_ident("range")
+                    || meta.path.is_ident("step")
+                    || meta.path.is_ident("unit")
+                {
+                    if let Kind::Default = kind {
+                        kind = Kind::Float {
+                            range: None,
+                            step: None,
+                            unit: None,
+                        };
+                    }
+                    let Kind::Float { range, step, unit } = &mut kind else {
+                        return Err(meta.error("float options can't be combined with other kinds"));
+                    };
+                    let value = meta.value()?;
+                    if meta.path.is_ident("range") {
+                        *range = Some(value.parse()?);
+                    } else if meta.path.is_ident("step") {
+                        *step = Some(value.parse()?);
+                    } else {
+                        *unit = Some(value.parse()?);
+                    }
+                } else {
+                    return Err(meta.error(
+                        "expected `name`, `slider`, `select`, `segmented`, `range`, `step`, or `unit`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+
+        if let Some(path) = &segmented
+            && !matches!(kind, Kind::Select(_))
+        {
+            return Err(syn::Error::new_spanned(
+                path,
+                "`segmented` can only be used along with `select`",
+            ));
+        }
+
+        Ok(Self {
+            ident,
+            ty: &field.ty,
+            name: name.unwrap_or_else(|| display_name(&ident.to_string())),
+            description: description(&field.attrs),
+            kind,
+            segmented: segmented.is_some(),
+        })
+    }
+
+    /// The type of the field's parameter.
+    fn param_type(&self) -> proc_macro2::TokenStream {
+        let ty = self.ty;
+        match &self.kind {
+            Kind::Default => quote! { <#ty as ::snowscape::dynamic::DefaultParam>::Param },
+            Kind::Slider(_) => quote! { ::snowscape::dynamic::param::SliderParam },
+            Kind::Select(_) => quote! { ::snowscape::dynamic::param::SelectParam<#ty> },
+            Kind::Float { .. } => quote! { ::snowscape::dynamic::param::FloatParam<#ty> },
+        }
+    }
+
+    /// Creates the field's parameter with the given default `value`.
+    fn param(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let (ty, name) = (self.ty, &self.name);
+        let segmented = self.segmented.then(|| quote! { .segmented() });
+        match &self.kind {
+            Kind::Default => {
+                quote! { <#ty as ::snowscape::dynamic::DefaultParam>::param(#name, #value) }
+            }
+            Kind::Slider(range) => quote! { ::snowscape::dynamic::slider(#name, #range, #value) },
+            Kind::Select(Some(options)) => {
+                quote! { ::snowscape::dynamic::select(#name, &#options, #value) #segmented }
+            }
+            Kind::Select(None) => {
+                quote! { ::snowscape::dynamic::enumeration::<#ty>(#name, #value) #segmented }
+            }
+            Kind::Float { range, step, unit } => {
+                let range = range.iter();
+                let step = step.iter();
+                let unit = unit.iter();
+                quote! {
+                    ::snowscape::dynamic::float::<#ty>(#name, #value)
+                        #(.range(#range))*
+                        #(.step(#step))*
+                        #(.unit(#unit))*
+                }
+            }
+        }
+    }
+}
+
+/// Implements `Params` for the struct with a hidden struct holding the parameter of each field.
+fn expand_params(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`Params` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`Params` can only be derived for structs with named fields",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`Params` can't be derived for generic structs",
+        ));
+    }
+
+    let fields = named
+        .named
+        .iter()
+        .map(Field::new)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let (vis, name) = (&input.vis, &input.ident);
+    let idents: Vec<_> = fields.iter().map(|field| field.ident).collect();
+    let indices = 0..fields.len();
+    let param_types = fields.iter().map(Field::param_type);
+    let params = fields.iter().map(|field| {
+        let ident = field.ident;
+        field.param(quote! { self.#ident })
+    });
+    let to_params = fields.iter().map(|field| {
+        let ident = field.ident;
+        let param = quote! { ::snowscape::dynamic::param::DynamicParam::to_param(&self.#ident) };
+        match &field.description {
+            Some(description) => quote! { #param.description(#description) },
+            None => param,
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            #[derive(Clone)]
+            #vis struct Fields {
+                #(#idents: #param_types,)*
+            }
+
+            impl ::snowscape::dynamic::ExtractParams for Fields {
+                type Values = #name;
+
+                fn to_params(&self) -> ::std::vec::Vec<::snowscape::dynamic::Param> {
+                    ::std::vec![#(#to_params),*]
+                }
+
+                fn update_index(&mut self, index: usize, value: ::snowscape::dynamic::Value) {
+                    match index {
+                        #(#indices => ::snowscape::dynamic::param::DynamicParam::update(
+                            &mut self.#idents,
+                            value,
+                        ),)*
+                        _ => {}
+                    }
+                }
+
+                fn extract(&self) -> #name {
+                    #name {
+                        #(#idents: ::snowscape::dynamic::param::DynamicParam::value(&self.#idents),)*
+                    }
+                }
+            }
+
+            impl ::snowscape::dynamic::Params for #name {
+                type Fields = Fields;
+
+                fn into_fields(self) -> Fields {
+                    Fields {
+                        #(#idents: #params,)*
+                    }
+                }
+            }
+        };
+    })
+}
+
+/// Implements `snowscape::dynamic::ParamEnum` for an enum without fields, so that its
+/// variants can be chosen by `dynamic::enumeration` or a `#[param(select)]` field.
+///
+/// Variants are labeled with their name in sentence case, e.g. "Extra large" for `ExtraLarge`,
+/// and their doc comments become the descriptions of the options. The label can be changed
+/// with `#[param(name = "XL")]`. The enum must also implement `Clone` and `PartialEq`.
+///
+/// ```ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, snowscape::ParamEnum)]
+/// enum Size {
+///     Small,
+///     /// Fills the available space.
+///     ExtraLarge,
+/// }
+///
+/// dynamic::enumeration("Size", Size::Small).segmented()
+/// ```
+#[proc_macro_derive(ParamEnum, attributes(param))]
+pub fn derive_param_enum(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match expand_param_enum(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Implements `ParamEnum` for the enum by listing its variants along with their labels.
+fn expand_param_enum(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`ParamEnum` can only be derived for enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`ParamEnum` can't be derived for generic enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`ParamEnum` can't be derived for enums without variants",
+        ));
+    }
+
+    let mut idents = Vec::new();
+    let mut labels = Vec::new();
+    let mut descriptions = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "`ParamEnum` variants can't have fields",
+            ));
+        }
+
+        let mut label = None;
+        for attribute in variant.attrs.iter().filter(|a| a.path().is_ident("param")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    label = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `name`"))
+                }
+            })?;
+        }
+
+        idents.push(&variant.ident);
+        labels.push(label.unwrap_or_else(|| display_name(&variant.ident.to_string())));
+        descriptions.push(match description(&variant.attrs) {
+            Some(description) => {
+                quote! { ::core::option::Option::Some(::std::string::String::from(#description)) }
+            }
+            None => quote! { ::core::option::Option::None },
+        });
+    }
+
+    let name = &input.ident;
+    Ok(quote! {
+        impl ::snowscape::dynamic::ParamEnum for #name {
+            fn variants() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(Self::#idents),*]
+            }
+
+            fn label(&self) -> ::std::string::String {
+                let label = match self {
+                    #(Self::#idents => #labels,)*
+                };
+                ::std::string::String::from(label)
+            }
+
+            fn description(&self) -> ::core::option::Option<::std::string::String> {
+                match self {
+                    #(Self::#idents => #descriptions,)*
+                }
+            }
+        }
+    })
+}
+
+/// The name shown for a field or variant in sentence case,
+/// e.g. "Font size" for `font_size` or "Extra large" for `ExtraLarge`.
+fn display_name(ident: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    for char in ident.trim_start_matches("r#").chars() {
+        // Words are separated by underscores or start with an uppercase letter after a lowercase one.
+        let continues_word = char != '_'
+            && previous.is_some_and(|previous| {
+                previous != '_'
+                    && !(char.is_uppercase() && (previous.is_lowercase() || previous.is_numeric()))
+            });
+        match words.last_mut() {
+            Some(word) if continues_word => word.push(char),
+            _ if char != '_' => words.push(char.to_string()),
+            _ => {}
+        }
+        previous = Some(char);
+    }
+
+    let mut name = String::new();
+    for (index, word) in words.iter().enumerate() {
+        let is_acronym = word.chars().all(|char| !char.is_lowercase());
+        let mut chars = word.chars();
+        let Some(first) = chars.next() else { continue };
+        if index == 0 {
+            name.extend(first.to_uppercase());
+        } else {
+            name.push(' ');
+            if is_acronym {
+                name.push(first);
+            } else {
+                name.extend(first.to_lowercase());
+            }
+        }
+        name.extend(chars);
+    }
+    name
+}
+
+/// The description from the doc comment attributes, if there is one.
+fn description(attributes: &[Attribute]) -> Option<String> {
+    let lines = attributes
+        .iter()
+        .filter_map(|attribute| match &attribute.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(line),
+                    ..
+                }) => Some(line.value()),
+                _ => None,
+            },
+            _ => None,
+        });
+    join_doc_lines(lines)
+}
+
+/// Joins the lines of a doc comment into Markdown, removing the space after each `///`
+/// and any blank lines at the start or end.
+fn join_doc_lines(lines: impl IntoIterator<Item = String>) -> Option<String> {
+    let lines: Vec<String> = lines
+        .into_iter()
+        .flat_map(|line| {
+            line.split('\n')
+                .map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let start = lines.iter().position(|line| !line.is_empty())?;
+    let end = lines.iter().rposition(|line| !line.is_empty())?;
+    Some(lines[start..=end].join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lines keep their Markdown structure without the leading space or surrounding blank lines.
+    #[test]
+    fn joins_doc_lines_into_markdown() {
+        let lines = [
+            "",
+            " A button that",
+            " increments.",
+            "",
+            " - First",
+            "   - Nested ",
+            "",
+        ];
+        assert_eq!(
+            join_doc_lines(lines.map(String::from)).as_deref(),
+            Some("A button that\nincrements.\n\n- First\n  - Nested")
+        );
+        assert_eq!(join_doc_lines([String::from(" ")]), None);
+    }
+
+    /// Field names become sentence case with spaces between their words.
+    #[test]
+    fn display_names_of_fields() {
+        assert_eq!(display_name("font_size"), "Font size");
+        assert_eq!(display_name("r#type"), "Type");
+        assert_eq!(display_name("_private_value"), "Private value");
+    }
+
+    /// Variant names become sentence case, keeping acronyms as they are.
+    #[test]
+    fn display_names_of_variants() {
+        assert_eq!(display_name("ExtraLarge"), "Extra large");
+        assert_eq!(display_name("Left"), "Left");
+        assert_eq!(display_name("UseHTTP"), "Use HTTP");
+        assert_eq!(display_name("Rgb8Bit"), "Rgb8 bit");
+    }
+}
@@ -1,26 +1,56 @@
 pub use crate::message::Message;
 use crate::{
-    Preview,
+    Preview, canvas, compare,
     config_tab::ConfigTab,
-    preview::Descriptor,
+    dynamic::appearance,
+    focus,
+    keybinding::{self, Action, Keybinding},
+    palette::{self, Command},
+    preview::{Decorator, Descriptor, Replay, ReplayError},
+    screenshot,
+    search::Query,
+    source::Snippet,
     test,
+    tree::Group,
+    viewport,
     widget::{
-        config_pane, header, preview_area, preview_list, recorder, search_input,
+        canvas_toolbar, command_palette, compare_toolbar, config_pane,
+        config_pane::parameter_pane::NumberDraft,
+        header, keybindings_help, preview_area, preview_list, preview_matrix, preview_viewports,
+        recorder, replay_failures, screenshot_result, search_input,
         split::{Strategy, horizontal_split, vertical_split},
+        stage,
     },
+    workspace::{self, SavedCanvas, Workspace},
 };
 use iced::{
-    Element,
+    Element, Event,
     Length::Fill,
-    Subscription, Task, Theme, keyboard, system,
+    Subscription, Task, Theme, clipboard, event, keyboard, system,
     theme::{self, Base},
-    widget::{button, column, container, opaque, operation, rule, scrollable, space, stack, text},
+    time::{self, Instant},
+    widget::{
+        button, column, container, mouse_area, opaque, operation, rule, scrollable, space, stack,
+        text,
+    },
     window,
 };
 use iced_anim::{Animated, Animation, Easing};
-use std::{path::PathBuf, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 pub const SEARCH_INPUT_ID: &str = "search_input";
+/// The ID of the scrollable sidebar, which scrolls to previews selected with the keyboard.
+const SIDEBAR_ID: &str = "sidebar";
+/// The ID of the container around the selected preview, which can be focused with the keyboard.
+const PREVIEW_ID: &str = "preview";
+/// How long the workspace must stay unchanged before it's saved, so dragging a
+/// split or typing a search doesn't write the state file on every message.
+const WORKSPACE_SAVE_DELAY: Duration = Duration::from_millis(500);
 
 /// A function to configure your app's previews.
 /// Send + Sync bound required to allow running tests off the main thread.
@@ -38,6 +68,8 @@ pub struct App {
     pub(crate) title: Option<String>,
     /// The current search query that filters previews.
     search: String,
+    /// The paths of the groups that are collapsed in the list of previews.
+    collapsed_groups: HashSet<String>,
     /// The width of the sidebar.
     sidebar_width: f32,
     /// The currently selected configuration tab.
@@ -46,6 +78,16 @@ pub struct App {
     config_pane_height: f32,
     /// The list of registered previewable elements.
     descriptors: Vec<Descriptor>,
+    /// Decorators that wrap the element of every preview.
+    decorators: Vec<Decorator>,
+    /// The themes rendered side by side in the theme matrix.
+    matrix_themes: Vec<Theme>,
+    /// Whether the theme matrix is shown instead of the single preview.
+    is_matrix: bool,
+    /// The viewport presets and how the preview area is sized.
+    viewport: viewport::State,
+    /// The zoom, pan, and background of the canvas for each preview.
+    canvas: canvas::State,
     /// The index of the selected `descriptor` in the list.
     selected_index: Option<usize>,
     /// The theme used by the application.
@@ -54,12 +96,42 @@ pub struct App {
     theme_mode: theme::Mode,
     /// The ID of the main window.
     main_window: Option<window::Id>,
+    /// The windows that previews have been popped out into, with the index of their preview.
+    pop_outs: HashMap<window::Id, usize>,
+    /// The state of compare mode while the selected preview is compared side by side.
+    compare: Option<compare::State>,
     /// App configuration callback used for test runs.
     configure: Option<ConfigureFn>,
     /// Test-related state.
     test: test::State,
+    /// The file the workspace is saved to between launches.
+    state_file: PathBuf,
+    /// The workspace as it was last saved to the `state_file`, which is only set
+    /// once the application has been set up to run with a window.
+    saved_workspace: Option<Workspace>,
+    /// When the workspace last changed, while the change hasn't been saved yet.
+    workspace_changed_at: Option<Instant>,
     /// Pending delete confirmation dialog state.
     delete_test_dialog: Option<DeleteTestDialog>,
+    /// The state of the command palette while it's shown in the dialog.
+    palette: Option<palette::State>,
+    /// Whether the list of keyboard shortcuts is shown in the dialog.
+    is_help_shown: bool,
+    /// The keyboard shortcuts of the application.
+    keybindings: Vec<Keybinding>,
+    /// The source code of the selected preview while the Code tab is shown.
+    snippet: Option<Snippet>,
+    /// The paths of the previews whose messages couldn't be replayed
+    /// after the last hot reload, along with the reason.
+    replay_failures: Vec<(String, ReplayError)>,
+    /// The outcome of the last screenshot taken from the app, until it's dismissed.
+    screenshot_result: Option<Result<PathBuf, String>>,
+    /// Whether the preview area has keyboard focus, so only shortcuts with a modifier
+    /// are handled and the preview receives plain keys like the arrow keys.
+    is_preview_focused: bool,
+    /// The text entered into a number field of the selected preview's parameters,
+    /// until it's a valid number or another parameter changes.
+    number_draft: Option<NumberDraft>,
     /// State for the global dialog widget.
     dialog: crate::widget::dialog::State,
 }
@@ -69,17 +141,36 @@ impl Default for App {
         Self {
             title: None,
             search: String::new(),
+            collapsed_groups: HashSet::new(),
             sidebar_width: 250.0,
             config_tab: ConfigTab::default(),
             config_pane_height: 200.0,
             descriptors: Vec::new(),
+            decorators: Vec::new(),
+            matrix_themes: Theme::ALL.to_vec(),
+            is_matrix: false,
+            viewport: viewport::State::default(),
+            canvas: canvas::State::default(),
             selected_index: None,
             theme: None,
             theme_mode: Default::default(),
             main_window: None,
+            pop_outs: HashMap::new(),
+            compare: None,
             configure: None,
             test: test::State::default(),
+            state_file: PathBuf::from(workspace::DEFAULT_STATE_FILE),
+            saved_workspace: None,
+            workspace_changed_at: None,
             delete_test_dialog: None,
+            palette: None,
+            is_help_shown: false,
+            keybindings: Keybinding::defaults(),
+            snippet: None,
+            replay_failures: Vec::new(),
+            screenshot_result: None,
+            is_preview_focused: false,
+            number_draft: None,
             dialog: crate::widget::dialog::State::default(),
         }
     }
@@ -92,9 +183,75 @@ impl App {
         self
     }
 
-    /// Adds a preview to the application.
-    pub fn preview(mut self, preview: impl Preview + 'static) -> Self {
-        self.descriptors.push(preview.into());
+    /// Adds a preview to the application, followed by any of its named variants.
+    pub fn preview(mut self, mut preview: impl Preview + 'static) -> Self {
+        let variants = preview.take_variants();
+        let decorators = preview.take_decorators();
+        self.descriptors
+            .push(Descriptor::new(preview).with_decorators(decorators.clone()));
+        self.descriptors.extend(
+            variants
+                .into_iter()
+                .map(|variant| Descriptor::from_boxed(variant).with_decorators(decorators.clone())),
+        );
+        self
+    }
+
+    /// Adds the preview built by `factory` to the application, followed by any of its named variants.
+    ///
+    /// Unlike [`App::preview`], compare mode can build a fresh instance of the preview
+    /// from the `factory` to show it side by side with another one.
+    pub fn preview_fn<P, F>(mut self, factory: F) -> Self
+    where
+        P: Preview + 'static,
+        F: Fn() -> P + Send + Sync + 'static,
+    {
+        let factory = Arc::new(factory);
+        let start = self.descriptors.len();
+        self = self.preview(factory());
+
+        // The preview comes first, followed by each of its variants in order.
+        for (position, descriptor) in self.descriptors[start..].iter_mut().enumerate() {
+            let factory = factory.clone();
+            descriptor.factory = Some(Arc::new(move || {
+                let mut preview = factory();
+                match position {
+                    0 => Some(Box::new(preview) as Box<dyn Preview>),
+                    _ => preview.take_variants().into_iter().nth(position - 1),
+                }
+            }));
+        }
+        self
+    }
+
+    /// Adds every preview defined with the [`preview`](macro@crate::preview) attribute,
+    /// ordered by the module and line they're defined on.
+    pub fn with_registered(self) -> Self {
+        crate::registry::register_all(self)
+    }
+
+    /// Adds a decorator that wraps the element of every preview, e.g. to apply
+    /// a theme, padding, or a background once for the entire application.
+    ///
+    /// App-wide decorators wrap any decorators added to individual previews.
+    pub fn decorator<D>(mut self, decorator: D) -> Self
+    where
+        D: for<'a> Fn(Element<'a, Message>) -> Element<'a, Message> + Send + Sync + 'static,
+    {
+        self.decorators.push(Arc::new(decorator));
+        self
+    }
+
+    /// Sets the themes rendered side by side in the theme matrix, which defaults to [`Theme::ALL`].
+    pub fn matrix_themes(mut self, themes: impl IntoIterator<Item = Theme>) -> Self {
+        self.matrix_themes = themes.into_iter().collect();
+        self
+    }
+
+    /// Sets the viewport presets the preview area can be constrained to,
+    /// replacing the default phone, tablet, and desktop presets.
+    pub fn viewports(mut self, viewports: impl IntoIterator<Item = crate::Viewport>) -> Self {
+        self.viewport.set_presets(viewports.into_iter().collect());
         self
     }
 
@@ -107,6 +264,22 @@ impl App {
         self
     }
 
+    /// Sets the file that the workspace, such as the selected preview, pane sizes, and
+    /// parameter values, is saved to between launches. Defaults to `target/snowscape/state.ron`.
+    pub fn state_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.state_file = path.as_ref().to_path_buf();
+        self
+    }
+
+    /// Sets the keyboard shortcuts of the application, replacing the defaults.
+    ///
+    /// Later keybindings take precedence, so append to [`Keybinding::defaults`] to
+    /// override some shortcuts while keeping the rest.
+    pub fn keybindings(mut self, keybindings: impl IntoIterator<Item = Keybinding>) -> Self {
+        self.keybindings = keybindings.into_iter().collect();
+        self
+    }
+
     #[cfg(feature = "internal")]
     pub fn with_test_state(mut self, test_state: test::State) -> Self {
         self.test = test_state;
@@ -123,11 +296,27 @@ impl App {
         self.theme.as_ref().map(|t| t.value().clone())
     }
 
-    /// The currently selected preview.
-    fn current_preview(&self) -> Option<&dyn Preview> {
+    /// The theme that the application is currently showing, falling back to the
+    /// default theme for the system's theme mode.
+    pub(crate) fn current_theme(&self) -> Theme {
+        self.theme
+            .as_ref()
+            .map(|theme| theme.value().clone())
+            .unwrap_or_else(|| Theme::default(self.theme_mode))
+    }
+
+    /// The decorated view of the currently selected preview.
+    fn current_preview_view(&self) -> Option<Element<'_, Message>> {
         self.selected_index
             .and_then(|index| self.descriptors.get(index))
-            .map(|descriptor| descriptor.preview.as_ref())
+            .map(|descriptor| descriptor.view(&self.decorators))
+    }
+
+    /// Renders the preview at the given `index` wrapped in all of its decorators.
+    ///
+    /// Panics if the `index` is out of bounds.
+    pub(crate) fn view_preview(&self, index: usize) -> Element<'_, Message> {
+        self.descriptors[index].view(&self.decorators)
     }
 
     /// Returns true if a test recording is currently active.
@@ -140,6 +329,18 @@ impl App {
         &self.test
     }
 
+    /// The text entered into a number field of the selected preview's parameters.
+    pub(crate) fn number_draft(&self) -> Option<&NumberDraft> {
+        self.number_draft.as_ref()
+    }
+
+    /// The source code of the selected preview shown in the Code tab.
+    pub(crate) fn snippet(&self) -> Option<&Snippet> {
+        self.snippet
+            .as_ref()
+            .filter(|snippet| Some(snippet.index) == self.selected_index)
+    }
+
     /// Returns the registered preview descriptors.
     pub fn descriptors(&self) -> &[Descriptor] {
         &self.descriptors
@@ -150,24 +351,40 @@ impl App {
         &mut self.descriptors
     }
 
-    /// Sets up the application with the given configuration function.
-    pub(crate) fn setup(configure: ConfigureFn) -> (Self, Task<Message>) {
+    /// Sets up the application with the given configuration function and command-line `options`.
+    pub(crate) fn setup(
+        configure: ConfigureFn,
+        options: &screenshot::GuiOptions,
+    ) -> (Self, Task<Message>) {
         let mut app = (configure)(App::default());
         app.configure = Some(configure.clone());
         if !app.descriptors.is_empty() {
             app.selected_index = Some(0);
         }
 
+        // Restore the workspace from the previous launch, which also enables saving it.
+        let workspace = Workspace::load(&app.state_file).unwrap_or_default();
+        let restore_task = app.restore_workspace(&workspace);
+        app.saved_workspace = Some(workspace);
+
+        // Command-line options take precedence over the restored workspace.
+        let options_task = app.apply_options(options);
+
         let refresh_task = app
-            .descriptors
-            .first()
-            .map(|descriptor| {
-                app.test
+            .selected_index
+            .and_then(|index| Some((index, app.descriptors.get_mut(index)?)))
+            .map(|(index, descriptor)| {
+                let boot_task = tag_task(index, descriptor.preview.take_boot_task());
+                let refresh_task = app
+                    .test
                     .update(
-                        test::Message::RefreshList(descriptor.metadata().label.clone()),
+                        test::Message::RefreshList(test::discovery::preview_folder(
+                            descriptor.metadata(),
+                        )),
                         None,
                     )
-                    .map(Message::Test)
+                    .map(Message::Test);
+                Task::batch([boot_task, refresh_task])
             })
             .unwrap_or_else(Task::none);
 
@@ -177,62 +394,598 @@ impl App {
             ..Default::default()
         });
         app.main_window = Some(main_id);
+        app.refresh_snippet();
 
         (
             app,
-            Task::batch([open_main.discard(), App::initial_theme(), refresh_task]),
+            Task::batch([
+                open_main.discard(),
+                App::initial_theme(),
+                restore_task,
+                options_task,
+                refresh_task,
+            ]),
         )
     }
 
+    /// Selects the preview, theme, and configuration tab given on the command line,
+    /// and applies the parameter values to the selected preview.
+    ///
+    /// Returns the task of the selected preview after its parameters were changed.
+    fn apply_options(&mut self, options: &screenshot::GuiOptions) -> Task<Message> {
+        if let Some(name) = &options.preview {
+            match screenshot::find_preview(&self.descriptors, name) {
+                Some(index) => self.selected_index = Some(index),
+                None => eprintln!("Warning: Preview '{name}' not found"),
+            }
+        }
+        if let Some(theme) = &options.theme {
+            self.theme = Some(animated_theme(theme.clone()));
+        }
+        if let Some(tab) = options
+            .tab
+            .as_ref()
+            .and_then(|name| ConfigTab::ALL.into_iter().find(|tab| tab.name() == name))
+        {
+            self.config_tab = tab;
+        }
+
+        let Some(descriptor) = self
+            .selected_index
+            .and_then(|index| self.descriptors.get_mut(index))
+        else {
+            return Task::none();
+        };
+
+        let mut tasks = Vec::new();
+        for (name, input) in &options.params {
+            let params = descriptor.preview.params();
+            let Some(index) = params
+                .iter()
+                .position(|param| param.name.eq_ignore_ascii_case(name))
+            else {
+                eprintln!(
+                    "Warning: Parameter '{name}' not found in preview '{}'",
+                    descriptor.metadata().qualified_label()
+                );
+                continue;
+            };
+            let Some(value) = params[index].value.parse(input) else {
+                eprintln!("Warning: Invalid value '{input}' for parameter '{name}'");
+                continue;
+            };
+
+            tasks.push(
+                descriptor
+                    .preview
+                    .update(Message::ChangeParam(index, value)),
+            );
+        }
+        Task::batch(tasks)
+    }
+
+    /// The current state of the workspace that is saved between launches.
+    fn workspace(&self) -> Workspace {
+        Workspace {
+            selected: self
+                .selected_index
+                .and_then(|index| self.descriptors.get(index))
+                .map(|descriptor| descriptor.metadata().path()),
+            sidebar_width: Some(self.sidebar_width),
+            config_pane_height: Some(self.config_pane_height),
+            config_tab: Some(self.config_tab.name().to_owned()),
+            theme: self.theme.as_ref().map(|theme| theme.target().to_string()),
+            search: self.search.clone(),
+            params: self
+                .descriptors
+                .iter()
+                .filter(|descriptor| !descriptor.preview.params().is_empty())
+                .map(|descriptor| {
+                    (
+                        descriptor.metadata().path(),
+                        workspace::save_params(descriptor.preview.params()),
+                    )
+                })
+                .collect(),
+            canvas: self
+                .canvas
+                .all_settings()
+                .iter()
+                .filter(|(_, settings)| **settings != canvas::Settings::default())
+                .map(|(label, settings)| (label.clone(), SavedCanvas::new(settings)))
+                .collect(),
+        }
+    }
+
+    /// Restores the state of a `workspace` saved by a previous launch.
+    ///
+    /// Returns the tasks of the previews whose parameters were restored.
+    fn restore_workspace(&mut self, workspace: &Workspace) -> Task<Message> {
+        if let Some(index) = workspace.selected.as_ref().and_then(|path| {
+            self.descriptors
+                .iter()
+                .position(|descriptor| descriptor.metadata().path() == *path)
+        }) {
+            self.selected_index = Some(index);
+        }
+        if let Some(width) = workspace.sidebar_width {
+            self.sidebar_width = width;
+        }
+        if let Some(height) = workspace.config_pane_height {
+            self.config_pane_height = height;
+        }
+        if let Some(tab) = workspace
+            .config_tab
+            .as_ref()
+            .and_then(|name| ConfigTab::ALL.into_iter().find(|tab| tab.name() == name))
+        {
+            self.config_tab = tab;
+        }
+        if let Some(theme) = workspace.theme.as_deref().and_then(appearance::parse_theme) {
+            self.theme = Some(animated_theme(theme));
+        }
+        self.search = workspace.search.clone();
+        self.canvas.set_all_settings(
+            workspace
+                .canvas
+                .iter()
+                .map(|(label, saved)| (label.clone(), saved.restore()))
+                .collect(),
+        );
+
+        let mut tasks = Vec::new();
+        for descriptor in &mut self.descriptors {
+            let Some(saved) = workspace.params.get(&descriptor.metadata().path()) else {
+                continue;
+            };
+
+            let changes: Vec<_> = descriptor
+                .preview
+                .params()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, param)| {
+                    let value = saved.get(&param.name)?.restore(&param.value)?;
+                    (value != param.value).then_some((index, value))
+                })
+                .collect();
+
+            tasks.extend(changes.into_iter().map(|(index, value)| {
+                descriptor
+                    .preview
+                    .update(Message::ChangeParam(index, value))
+            }));
+        }
+        Task::batch(tasks)
+    }
+
+    /// Saves the workspace to the state file if it has changed since it was last saved.
+    ///
+    /// Does nothing unless the application was set up to run with a window, so
+    /// tests and screenshots never change the state file.
+    fn save_workspace(&mut self) {
+        self.workspace_changed_at = None;
+        let Some(saved) = &self.saved_workspace else {
+            return;
+        };
+
+        let workspace = self.workspace();
+        if *saved == workspace {
+            return;
+        }
+
+        if let Err(e) = workspace.save(&self.state_file) {
+            eprintln!(
+                "Failed to save state file '{}': {e}",
+                self.state_file.display()
+            );
+        }
+        self.saved_workspace = Some(workspace);
+    }
+
+    /// Updates the application and schedules saving the workspace if the `message`
+    /// changes any of its state.
     pub(crate) fn update(&mut self, message: Message) -> Task<Message> {
+        let changes_workspace = matches!(
+            message,
+            Message::SelectPreview(_)
+                | Message::ChangeSearch(_)
+                | Message::ChangeParam(..)
+                | Message::ResetParams
+                | Message::ResizeSidebar(_)
+                | Message::ResizeConfigPane(_)
+                | Message::ChangeConfigTab(_)
+                | Message::UpdateTheme(_)
+                | Message::Canvas(_)
+        );
+
+        let task = self.handle_message(message);
+        if changes_workspace {
+            self.workspace_changed_at = Some(Instant::now());
+        }
+        self.refresh_snippet();
+        Task::batch([task, self.refresh_compare()])
+    }
+
+    /// Creates a separate instance of the preview shown on the right in compare mode
+    /// whenever it changes, so each side has its own state and parameters.
+    ///
+    /// Returns the boot task of the new instance.
+    fn refresh_compare(&mut self) -> Task<Message> {
+        let (Some(compare), Some(selected)) = (&mut self.compare, self.selected_index) else {
+            return Task::none();
+        };
+        let index = compare.target_index(selected);
+        if compare
+            .instance
+            .as_ref()
+            .is_some_and(|(instance_index, _)| *instance_index == index)
+        {
+            return Task::none();
+        }
+
+        // Previews can't be cloned, so the instance is built by the preview's factory.
+        // Previews added without one can't be compared.
+        let instance = self
+            .descriptors
+            .get(index)
+            .and_then(Descriptor::instantiate);
+        let Some(mut instance) = instance else {
+            compare.instance = None;
+            return Task::none();
+        };
+
+        let boot_task = instance.preview.take_boot_task().map(compare::right);
+        compare.instance = Some((index, instance));
+        boot_task
+    }
+
+    /// Rebuilds the previews after the code was hot reloaded, since they still call the
+    /// functions from before the reload, and restores the previous session.
+    ///
+    /// The selection and parameter values are restored by name. Pop-out windows and the
+    /// messages each preview emitted are matched to the new previews by their full path,
+    /// so previews with the same label in different groups aren't mixed up.
+    fn reload(&mut self) -> Task<Message> {
+        let Some(configure) = self.configure.clone() else {
+            return Task::none();
+        };
+
+        let workspace = self.workspace();
+        let mut replays: HashMap<String, Replay> = self
+            .descriptors
+            .iter()
+            .filter_map(|descriptor| {
+                let replay = descriptor.preview.to_replay()?;
+                Some((descriptor.metadata().path(), replay))
+            })
+            .collect();
+        let pop_outs: Vec<(window::Id, String)> = self
+            .pop_outs
+            .iter()
+            .filter_map(|(&id, &index)| {
+                let descriptor = self.descriptors.get(index)?;
+                Some((id, descriptor.metadata().path()))
+            })
+            .collect();
+
+        let reloaded = (configure)(App::default());
+        self.descriptors = reloaded.descriptors;
+        self.decorators = reloaded.decorators;
+        self.selected_index = (!self.descriptors.is_empty()).then_some(0);
+        self.snippet = None;
+        if let Some(compare) = &mut self.compare {
+            compare.instance = None;
+        }
+
+        // Popped out previews that no longer exist have their window closed.
+        let mut close_tasks = Vec::new();
+        for (id, path) in pop_outs {
+            match self
+                .descriptors
+                .iter()
+                .position(|descriptor| descriptor.metadata().path() == path)
+            {
+                Some(index) => _ = self.pop_outs.insert(id, index),
+                None => {
+                    self.pop_outs.remove(&id);
+                    close_tasks.push(window::close(id));
+                }
+            }
+        }
+
+        let restore_task = self.restore_workspace(&workspace);
+
+        self.replay_failures.clear();
+        for descriptor in &mut self.descriptors {
+            let path = descriptor.metadata().path();
+            let Some(replay) = replays.remove(&path) else {
+                continue;
+            };
+            if let Err(error) = descriptor.preview.replay(replay) {
+                self.replay_failures.push((path, error));
+            }
+        }
+
+        let boot_task = self
+            .selected_index
+            .and_then(|index| Some((index, self.descriptors.get_mut(index)?)))
+            .map(|(index, descriptor)| tag_task(index, descriptor.preview.take_boot_task()))
+            .unwrap_or_else(Task::none);
+        Task::batch(close_tasks.into_iter().chain([restore_task, boot_task]))
+    }
+
+    /// Updates compare mode, sending messages from the right side to its own instance.
+    fn update_compare(&mut self, message: compare::Message) -> Task<Message> {
+        let Some(compare) = &mut self.compare else {
+            if let compare::Message::Toggle = message {
+                self.compare = Some(compare::State::default());
+            }
+            return Task::none();
+        };
+
+        match message {
+            compare::Message::Toggle => {
+                self.compare = None;
+                Task::none()
+            }
+            compare::Message::SelectTarget(target) => {
+                compare.target = target;
+                Task::none()
+            }
+            compare::Message::ToggleSync(is_synced) => {
+                compare.is_synced = is_synced;
+                Task::none()
+            }
+            compare::Message::SelectSide(side) => {
+                compare.side = side;
+                Task::none()
+            }
+            compare::Message::ResizeSplit(split_at) => {
+                compare.split_at = split_at;
+                Task::none()
+            }
+            compare::Message::Right(message) => match *message {
+                message @ Message::Component(_) => {
+                    let synced_task = if compare.is_synced {
+                        self.selected_index
+                            .and_then(|index| self.descriptors.get_mut(index))
+                            .map(|descriptor| descriptor.preview.update(message.clone()))
+                            .unwrap_or_else(Task::none)
+                    } else {
+                        Task::none()
+                    };
+                    let right_task = compare
+                        .instance_mut()
+                        .map(|right| right.preview.update(message).map(compare::right))
+                        .unwrap_or_else(Task::none);
+                    Task::batch([right_task, synced_task])
+                }
+                // Both sides share the canvas, so panning either one pans both.
+                message => self.handle_message(message),
+            },
+        }
+    }
+
+    /// Sends the `message` to the preview that the configuration pane controls, which is
+    /// the right side in compare mode if it's selected.
+    fn update_configured(&mut self, message: Message) -> Task<Message> {
+        if let Some(compare) = &mut self.compare
+            && compare.side == compare::Side::Right
+        {
+            return compare
+                .instance_mut()
+                .map(|right| right.preview.update(message).map(compare::right))
+                .unwrap_or_else(Task::none);
+        }
+
+        match self
+            .selected_index
+            .and_then(|index| self.descriptors.get_mut(index))
+        {
+            Some(descriptor) => descriptor.preview.update(message),
+            None => Task::none(),
+        }
+    }
+
+    /// The descriptor of the preview that the configuration pane controls.
+    fn configured_descriptor(&self) -> Option<&Descriptor> {
+        match &self.compare {
+            Some(compare) if compare.side == compare::Side::Right => {
+                compare.instance.as_ref().map(|(_, descriptor)| descriptor)
+            }
+            _ => self
+                .selected_index
+                .and_then(|index| self.descriptors.get(index)),
+        }
+    }
+
+    /// Reads the source code of the selected preview while the Code tab is shown,
+    /// and renders it again whenever the preview's parameters change.
+    fn refresh_snippet(&mut self) {
+        if self.config_tab != ConfigTab::Code {
+            return;
+        }
+        let Some((index, descriptor)) = self
+            .selected_index
+            .and_then(|index| Some((index, self.descriptors.get(index)?)))
+        else {
+            self.snippet = None;
+            return;
+        };
+
+        let params = descriptor.preview.params();
+        match &mut self.snippet {
+            Some(snippet) if snippet.index == index => snippet.update(params),
+            _ => self.snippet = Some(Snippet::new(index, descriptor.metadata(), params)),
+        }
+    }
+
+    fn handle_message(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::SelectPreview(index) => {
                 if index < self.descriptors.len() {
                     self.selected_index = Some(index);
-                    let preview_name = self.descriptors[index].metadata().label.clone();
-                    return self
-                        .test
-                        .update(test::Message::RefreshList(preview_name), None)
-                        .map(Message::Test);
+                    self.is_preview_focused = false;
+                    self.number_draft = None;
+                    let descriptor = &mut self.descriptors[index];
+                    // The preview may no longer be selected once its boot task finishes.
+                    let boot_task = tag_task(index, descriptor.preview.take_boot_task());
+                    let preview_folder = test::discovery::preview_folder(descriptor.metadata());
+                    return Task::batch([
+                        boot_task,
+                        self.test
+                            .update(test::Message::RefreshList(preview_folder), None)
+                            .map(Message::Test),
+                    ]);
                 }
                 Task::none()
             }
-            Message::ResetPreview => {
-                let Some(descriptor) = self
+            Message::ResetPreview => self.update_configured(Message::ResetPreview),
+            Message::Canvas(message) => {
+                if let Some(descriptor) = self
                     .selected_index
-                    .and_then(|i| self.descriptors.get_mut(i))
-                else {
+                    .and_then(|index| self.descriptors.get(index))
+                {
+                    self.canvas.update(&descriptor.metadata().path(), message);
+                }
+                Task::none()
+            }
+            Message::Viewport(message) => {
+                self.viewport.update(message);
+                Task::none()
+            }
+            Message::ToggleMatrix => {
+                self.is_matrix = !self.is_matrix;
+                Task::none()
+            }
+            Message::Palette(message) => self.update_palette(message),
+            Message::TakeScreenshot => {
+                let Some(index) = self.selected_index else {
                     return Task::none();
                 };
 
-                descriptor.preview.update(Message::ResetPreview)
+                let canvas = self.canvas_settings();
+                let options = screenshot::Options {
+                    preview: self.descriptors[index]
+                        .metadata()
+                        .qualified_label()
+                        .into_owned(),
+                    output: None,
+                    theme: self
+                        .theme
+                        .as_ref()
+                        .map(|theme| theme.target().clone())
+                        .unwrap_or_else(|| Theme::default(self.theme_mode)),
+                    // The preview is captured at the size it's constrained to, if any.
+                    viewport_size: self
+                        .viewport
+                        .active()
+                        .first()
+                        .map_or(screenshot::DEFAULT_SIZE, |viewport| viewport.size),
+                    zoom: canvas.zoom,
+                    background: canvas.background,
+                };
+
+                // Only rendering needs the preview, so encoding and saving the image
+                // happens in the background to keep the app responsive.
+                let snapshot = match screenshot::snapshot_preview(self, index, &options) {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => {
+                        self.screenshot_result = Some(Err(e.to_string()));
+                        return Task::none();
+                    }
+                };
+                let output_path = screenshot::output_path(&self.descriptors[index], &options);
+                Task::perform(
+                    async move {
+                        tokio::task::spawn_blocking(move || {
+                            screenshot::save_snapshot(&snapshot, &output_path)
+                                .map_err(|e| e.to_string())
+                        })
+                        .await
+                        .unwrap_or_else(|e| Err(e.to_string()))
+                    },
+                    Message::ScreenshotSaved,
+                )
             }
-            Message::FocusInput => operation::focus(SEARCH_INPUT_ID),
-            Message::ChangeSearch(text) => {
-                self.search = text;
+            Message::ScreenshotSaved(result) => {
+                self.screenshot_result = Some(result);
                 Task::none()
             }
-            Message::ChangeParam(index, param) => {
-                let Some(descriptor) = self
-                    .selected_index
-                    .and_then(|i| self.descriptors.get_mut(i))
-                else {
+            Message::DismissScreenshotResult => {
+                self.screenshot_result = None;
+                Task::none()
+            }
+            Message::OpenUrl(url) => {
+                open_url(&url);
+                Task::none()
+            }
+            Message::CopyCode => match self.snippet().and_then(|snippet| snippet.code.clone()) {
+                Some(code) => clipboard::write(code),
+                None => Task::none(),
+            },
+            Message::FocusInput => {
+                self.is_preview_focused = false;
+                operation::focus(SEARCH_INPUT_ID)
+            }
+            Message::KeyPressed(window, key, modifiers) => {
+                // Shortcuts only apply to the main window, not the window of a test recording.
+                if self.main_window.is_some_and(|main| main != window) {
                     return Task::none();
+                }
+
+                let action = if !self.is_preview_focused {
+                    keybinding::action(&self.keybindings, &key, modifiers)
+                } else if key == keyboard::Key::Named(keyboard::key::Named::Escape) {
+                    // Escape moves keyboard focus from the preview back to the list of previews.
+                    self.is_preview_focused = false;
+                    None
+                } else {
+                    keybinding::preview_action(&self.keybindings, &key, modifiers)
                 };
 
-                descriptor
-                    .preview
-                    .update(Message::ChangeParam(index, param))
+                match action {
+                    Some(action) => self.run_action(action),
+                    None => Task::none(),
+                }
+            }
+            Message::FocusPreviewArea(focused) => {
+                self.is_preview_focused = focused;
+                Task::none()
+            }
+            Message::ToggleHelp => {
+                if self.is_help_shown && self.dialog.is_open() {
+                    self.dialog.close();
+                } else if !self.dialog.is_open() {
+                    self.palette = None;
+                    self.is_help_shown = true;
+                    self.dialog.open();
+                }
+                Task::none()
+            }
+            Message::ChangeSearch(text) => {
+                self.search = text;
+                self.is_preview_focused = false;
+                Task::none()
+            }
+            Message::ToggleGroup(path) => {
+                if !self.collapsed_groups.remove(&path) {
+                    self.collapsed_groups.insert(path);
+                }
+                Task::none()
+            }
+            Message::ChangeParam(index, param) => {
+                self.number_draft = None;
+                self.update_configured(Message::ChangeParam(index, param))
             }
             Message::ResetParams => {
-                let Some(descriptor) = self
-                    .selected_index
-                    .and_then(|i| self.descriptors.get_mut(i))
-                else {
-                    return Task::none();
-                };
-
-                descriptor.preview.update(Message::ResetParams)
+                self.number_draft = None;
+                self.update_configured(Message::ResetParams)
+            }
+            Message::DraftNumber(draft) => {
+                self.number_draft = Some(draft);
+                Task::none()
             }
             Message::ResizeSidebar(size) => {
                 self.sidebar_width = size;
@@ -246,45 +999,40 @@ impl App {
                 self.config_tab = tab;
                 Task::none()
             }
-            Message::TimeTravel(index) => {
-                let Some(descriptor) = self
-                    .selected_index
-                    .and_then(|i| self.descriptors.get_mut(i))
-                else {
-                    return Task::none();
+            Message::TimeTravel(index) => self.update_configured(Message::TimeTravel(index)),
+            Message::JumpToPresent => self.update_configured(Message::JumpToPresent),
+            message @ Message::Component(_) => {
+                // Synced interactions are sent to the right side of the comparison as well.
+                let synced_task = match &mut self.compare {
+                    Some(compare) if compare.is_synced => compare
+                        .instance_mut()
+                        .map(|right| right.preview.update(message.clone()).map(compare::right))
+                        .unwrap_or_else(Task::none),
+                    _ => Task::none(),
                 };
 
-                descriptor.preview.update(Message::TimeTravel(index))
-            }
-            Message::JumpToPresent => {
-                let Some(descriptor) = self
-                    .selected_index
-                    .and_then(|i| self.descriptors.get_mut(i))
-                else {
-                    return Task::none();
-                };
-
-                descriptor.preview.update(Message::JumpToPresent)
-            }
-            Message::Component(message) => {
                 // Forward component messages to the current preview
                 if let Some(descriptor) = self
                     .selected_index
                     .and_then(|index| self.descriptors.get_mut(index))
                 {
-                    descriptor.preview.update(Message::Component(message))
+                    Task::batch([descriptor.preview.update(message), synced_task])
                 } else {
-                    Task::none()
+                    synced_task
                 }
             }
+            Message::Preview(index, message) => match *message {
+                message @ Message::Component(_) => match self.descriptors.get_mut(index) {
+                    Some(descriptor) => tag_task(index, descriptor.preview.update(message)),
+                    None => Task::none(),
+                },
+                message => self.handle_message(message),
+            },
             Message::Noop => Task::none(),
             Message::UpdateTheme(event) => {
-                let theme = self.theme.get_or_insert_with(|| {
-                    Animated::new(
-                        Theme::default(self.theme_mode),
-                        Easing::EASE.with_duration(Duration::from_millis(300)),
-                    )
-                });
+                let theme = self
+                    .theme
+                    .get_or_insert_with(|| animated_theme(Theme::default(self.theme_mode)));
                 theme.update(event);
                 Task::none()
             }
@@ -292,6 +1040,34 @@ impl App {
                 self.theme_mode = mode;
                 Task::none()
             }
+            Message::Compare(message) => self.update_compare(message),
+            Message::HotReload => self.reload(),
+            Message::DismissReplayFailures => {
+                self.replay_failures.clear();
+                Task::none()
+            }
+            Message::PopOut => {
+                let Some(index) = self.selected_index else {
+                    return Task::none();
+                };
+
+                let (id, open) = window::open(window::Settings {
+                    size: screenshot::DEFAULT_SIZE,
+                    exit_on_close_request: false,
+                    ..Default::default()
+                });
+                self.pop_outs.insert(id, index);
+                open.discard()
+            }
+            Message::SaveWorkspace(now) => {
+                let is_settled = self.workspace_changed_at.is_some_and(|changed_at| {
+                    now.duration_since(changed_at) >= WORKSPACE_SAVE_DELAY
+                });
+                if is_settled {
+                    self.save_workspace();
+                }
+                Task::none()
+            }
             Message::WindowClosed(id) => {
                 // If the test window was the one closed, stop the recording
                 if self.test.window_id == Some(id) {
@@ -299,9 +1075,11 @@ impl App {
                         .update(test::Message::StopRecording, None)
                         .map(Message::Test)
                 } else if self.main_window == Some(id) {
-                    // Main window is closing, so shut down the application
+                    // Main window is closing, so save any pending changes and shut down the application
+                    self.save_workspace();
                     window::close(id).chain(iced::exit())
                 } else {
+                    self.pop_outs.remove(&id);
                     window::close(id)
                 }
             }
@@ -321,7 +1099,8 @@ impl App {
                     self.descriptors
                         .get(index)
                         .map(|d| test::state::UpdateContext {
-                            preview_name: &d.metadata().label,
+                            preview_name: d.metadata().qualified_label(),
+                            preview_folder: test::discovery::preview_folder(d.metadata()),
                             preview_index: index,
                             configure: self.configure.clone(),
                         })
@@ -329,6 +1108,8 @@ impl App {
                 Task::batch([reset_task, self.test.update(msg, ctx).map(Message::Test)])
             }
             Message::OpenDeleteTestDialog(path) => {
+                self.palette = None;
+                self.is_help_shown = false;
                 let name = path
                     .file_name()
                     .and_then(|name| name.to_str())
@@ -343,6 +1124,8 @@ impl App {
                 let action = self.dialog.update(message);
                 if let Some(crate::widget::dialog::Action::Closed) = action {
                     self.delete_test_dialog = None;
+                    self.palette = None;
+                    self.is_help_shown = false;
                 }
 
                 Task::none()
@@ -359,7 +1142,8 @@ impl App {
                     self.descriptors
                         .get(index)
                         .map(|d| test::state::UpdateContext {
-                            preview_name: &d.metadata().label,
+                            preview_name: d.metadata().qualified_label(),
+                            preview_folder: test::discovery::preview_folder(d.metadata()),
                             preview_index: index,
                             configure: self.configure.clone(),
                         })
@@ -372,24 +1156,285 @@ impl App {
         }
     }
 
+    /// Runs the `action` of a keyboard shortcut.
+    fn run_action(&mut self, action: Action) -> Task<Message> {
+        // Only the dialogs themselves can be toggled while a dialog is open.
+        if self.dialog.is_open() && !matches!(action, Action::CommandPalette | Action::ShowHelp) {
+            return Task::none();
+        }
+
+        match action {
+            Action::FocusPreview => {
+                self.is_preview_focused = true;
+                iced::advanced::widget::operate(focus::focus_first_within(PREVIEW_ID)).discard()
+            }
+            Action::FocusSearch => self.update(Message::FocusInput),
+            Action::ResetPreview => self.update(Message::ResetPreview),
+            Action::CommandPalette => self.update(Message::Palette(palette::Message::Toggle)),
+            Action::ShowHelp => self.update(Message::ToggleHelp),
+            Action::SelectNext
+            | Action::SelectPrevious
+            | Action::SelectFirst
+            | Action::SelectLast
+            | Action::NextGroup
+            | Action::PreviousGroup => self.navigate(action),
+        }
+    }
+
+    /// Selects a different visible preview in the sidebar and scrolls it into view.
+    fn navigate(&mut self, action: Action) -> Task<Message> {
+        let (index, offset) = {
+            let query = self.query();
+            let root = Group::tree(
+                self.visible_previews()
+                    .into_iter()
+                    .map(|(index, descriptor)| (index, descriptor.metadata())),
+            );
+            // Groups are expanded the same way as in the sidebar.
+            let visible =
+                root.visible(&|path| !query.is_empty() || !self.collapsed_groups.contains(path));
+
+            let Some(position) = keybinding::navigate(action, &visible, self.selected_index) else {
+                return Task::none();
+            };
+            let offset = position as f32 / visible.len().saturating_sub(1).max(1) as f32;
+            (visible[position].0, offset)
+        };
+
+        if Some(index) == self.selected_index {
+            return Task::none();
+        }
+
+        Task::batch([
+            self.update(Message::SelectPreview(index)),
+            operation::snap_to(SIDEBAR_ID, scrollable::RelativeOffset { x: 0.0, y: offset }),
+        ])
+    }
+
+    /// Updates the command palette, running the chosen command once it's submitted.
+    fn update_palette(&mut self, message: palette::Message) -> Task<Message> {
+        match message {
+            palette::Message::Toggle => {
+                if self.delete_test_dialog.is_some() {
+                    return Task::none();
+                }
+
+                if self.palette.is_some() && self.dialog.is_open() {
+                    self.dialog.close();
+                    return Task::none();
+                }
+
+                self.palette = Some(palette::State::default());
+                self.is_help_shown = false;
+                self.dialog.open();
+                operation::focus(palette::INPUT_ID)
+            }
+            palette::Message::ChangeQuery(query) => {
+                if let Some(state) = &mut self.palette {
+                    state.query = query;
+                    state.selected = 0;
+                }
+                Task::none()
+            }
+            palette::Message::SelectNext | palette::Message::SelectPrevious => {
+                let count = self.palette_matches().len();
+                if let Some(state) = &mut self.palette {
+                    state.select(matches!(message, palette::Message::SelectNext), count);
+                }
+                Task::none()
+            }
+            palette::Message::Submit => match &self.palette {
+                Some(state) => self.update_palette(palette::Message::Run(state.selected)),
+                None => Task::none(),
+            },
+            palette::Message::Run(index) => {
+                // The palette remains until the dialog has finished closing, so ignore
+                // any commands that are chosen while it's animating out.
+                if !self.dialog.is_open() {
+                    return Task::none();
+                }
+
+                let Some(palette::Match { command, .. }) =
+                    self.palette_matches().into_iter().nth(index)
+                else {
+                    return Task::none();
+                };
+
+                self.dialog.close();
+                self.update(command.message)
+            }
+        }
+    }
+
+    /// The commands that can be run from the command palette.
+    fn commands(&self) -> Vec<Command> {
+        let previews = self
+            .descriptors
+            .iter()
+            .enumerate()
+            .map(|(index, descriptor)| {
+                Command::new(
+                    "Preview",
+                    descriptor.metadata().path(),
+                    Message::SelectPreview(index),
+                )
+            });
+        let tabs = ConfigTab::ALL
+            .into_iter()
+            .map(|tab| Command::new("Tab", tab.name(), Message::ChangeConfigTab(tab)));
+        let themes = Theme::ALL.iter().map(|theme| {
+            Command::new(
+                "Theme",
+                theme.to_string(),
+                Message::UpdateTheme(theme.clone().into()),
+            )
+        });
+
+        // Actions only apply to the selected preview.
+        let actions = self.selected_index.is_some().then(|| {
+            [
+                Command::new(
+                    "Action",
+                    "Run all tests",
+                    Message::Test(test::Message::RunAll),
+                ),
+                Command::new("Action", "Reset params", Message::ResetParams),
+                Command::new("Action", "Reset preview", Message::ResetPreview),
+                Command::new(
+                    "Action",
+                    "Start recording",
+                    Message::Test(test::Message::StartRecording),
+                ),
+                Command::new("Action", "Take screenshot", Message::TakeScreenshot),
+                Command::new("Action", "Pop out preview", Message::PopOut),
+                Command::new("Action", "Jump to present", Message::JumpToPresent),
+                Command::new("Action", "Toggle theme matrix", Message::ToggleMatrix),
+                Command::new(
+                    "Action",
+                    "Toggle compare mode",
+                    Message::Compare(compare::Message::Toggle),
+                ),
+            ]
+        });
+        let help = Command::new("Action", "Show keyboard shortcuts", Message::ToggleHelp);
+
+        actions
+            .into_iter()
+            .flatten()
+            .chain([help])
+            .chain(previews)
+            .chain(tabs)
+            .chain(themes)
+            .collect()
+    }
+
+    /// The commands that match the query of the command palette, with the most relevant first.
+    fn palette_matches(&self) -> Vec<palette::Match> {
+        match &self.palette {
+            Some(state) => palette::matches(self.commands(), &state.query),
+            None => Vec::new(),
+        }
+    }
+
     pub(crate) fn subscription(&self) -> Subscription<Message> {
-        Subscription::batch([
-            system::theme_changes().map(Message::ChangeThemeMode),
-            keyboard::listen().filter_map(|event| match event {
-                keyboard::Event::KeyPressed { key, modifiers, .. } => match key.as_ref() {
-                    keyboard::Key::Character("/") => Some(Message::FocusInput),
-                    keyboard::Key::Character("r") if modifiers.command() => {
-                        Some(Message::ResetPreview)
+        // Only the selected preview, the one being recorded, and popped out previews
+        // listen to their subscriptions.
+        let session_index = self.test.session.as_ref().map(|s| s.preview_index);
+        let mut indices: Vec<usize> = self
+            .selected_index
+            .into_iter()
+            .chain(session_index)
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let previews = indices
+            .iter()
+            .filter_map(|&index| self.descriptors.get(index))
+            .map(|descriptor| descriptor.preview.subscription());
+
+        // Popped out previews may not be selected, so their messages are tagged with their index.
+        let mut pop_out_indices: Vec<usize> = self
+            .pop_outs
+            .values()
+            .copied()
+            .filter(|index| !indices.contains(index))
+            .collect();
+        pop_out_indices.sort_unstable();
+        pop_out_indices.dedup();
+        let pop_outs: Vec<_> = pop_out_indices
+            .into_iter()
+            .filter_map(|index| {
+                let subscription = self.descriptors.get(index)?.preview.subscription();
+                Some(
+                    subscription
+                        .with(index)
+                        .map(|(index, message)| Message::preview(index, message)),
+                )
+            })
+            .collect();
+
+        // The right side of the comparison is a separate instance with its own subscriptions.
+        let compared = self
+            .compare
+            .as_ref()
+            .and_then(|compare| compare.instance.as_ref())
+            .map(|(_, right)| right.preview.subscription().map(compare::right));
+
+        // The arrow keys move through the commands of the palette while its input is focused.
+        let palette = self.palette.is_some().then(|| {
+            event::listen_with(|event, _status, _window| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => match key.as_ref() {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                        Some(Message::Palette(palette::Message::SelectNext))
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                        Some(Message::Palette(palette::Message::SelectPrevious))
                     }
                     _ => None,
                 },
                 _ => None,
-            }),
-            window::close_requests().map(Message::WindowClosed),
-        ])
+            })
+        });
+
+        // Unsaved workspace changes are saved once they settle.
+        let save = self
+            .workspace_changed_at
+            .is_some()
+            .then(|| time::every(WORKSPACE_SAVE_DELAY).map(Message::SaveWorkspace));
+
+        // Previews are rebuilt whenever the code is hot reloaded.
+        #[cfg(feature = "internal")]
+        let reloads = Some(crate::reload::hot_reloads());
+        #[cfg(not(feature = "internal"))]
+        let reloads = None;
+
+        Subscription::batch(
+            previews
+                .chain(pop_outs)
+                .chain(compared)
+                .chain(palette)
+                .chain(save)
+                .chain(reloads)
+                .chain([
+                    system::theme_changes().map(Message::ChangeThemeMode),
+                    // Shortcuts are ignored while a widget handles the key, e.g. by typing in an input.
+                    event::listen_with(|event, status, window| match (event, status) {
+                        (
+                            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }),
+                            event::Status::Ignored,
+                        ) => Some(Message::KeyPressed(window, key, modifiers)),
+                        _ => None,
+                    }),
+                    window::close_requests().map(Message::WindowClosed),
+                ]),
+        )
     }
 
     pub(crate) fn view(&self, window_id: window::Id) -> Element<'_, Message> {
+        if let Some(&index) = self.pop_outs.get(&window_id) {
+            return self.view_pop_out(index);
+        }
+
         // Check if this is the test window
         if self.test.session.is_some() && Some(window_id) != self.main_window {
             return self.view_test_window();
@@ -399,18 +1444,139 @@ impl App {
         self.view_main_window()
     }
 
+    /// The selected preview, rendered once for each matrix theme if the theme matrix is shown,
+    /// or constrained to the selected viewports.
+    fn preview_body(&self) -> Element<'_, Message> {
+        let body = match self.selected_index {
+            Some(index) if self.is_matrix => {
+                preview_matrix(&self.matrix_themes, || self.view_preview(index))
+            }
+            Some(index) if self.viewport.selection != viewport::Selection::Fill => {
+                preview_viewports(self.viewport.active(), || self.view_preview(index))
+            }
+            Some(index) if self.compare.is_some() => self.compare_body(index),
+            _ => preview_area(
+                self.current_preview_view(),
+                self.canvas_settings(),
+                self.fit_scale(),
+            ),
+        };
+
+        // Clicking the preview gives it keyboard focus, unless a widget within it handles the click.
+        mouse_area(container(body).id(PREVIEW_ID))
+            .on_press(Message::FocusPreviewArea(true))
+            .into()
+    }
+
+    /// The selected preview on the left and the preview it's compared with on the right,
+    /// below a toolbar for choosing what to compare.
+    fn compare_body(&self, index: usize) -> Element<'_, Message> {
+        let Some(compare) = &self.compare else {
+            return self.view_preview(index);
+        };
+        let targets = std::iter::once(compare::Target::SamePreview)
+            .chain(
+                self.descriptors
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != index)
+                    .map(|(index, descriptor)| compare::Target::Preview {
+                        index,
+                        path: descriptor.metadata().path(),
+                    }),
+            )
+            .collect();
+
+        let left = preview_area(
+            Some(self.view_preview(index)),
+            self.canvas_settings(),
+            self.fit_scale(),
+        );
+        // The compared preview is panned and zoomed along with the selected one,
+        // but only the selected one reports the scale it's fitted with.
+        let right = match &compare.instance {
+            Some((_, right)) => stage(
+                right.view(&self.decorators).map(compare::right),
+                self.canvas_settings(),
+            )
+            .on_pan(|offset| Message::Canvas(canvas::Message::Pan(offset)))
+            .into(),
+            None => {
+                let target = compare.target_index(index);
+                let message = match self.descriptors.get(target) {
+                    Some(descriptor) if descriptor.factory.is_none() => {
+                        "Compare unavailable: add this preview with `App::preview_fn` to compare it"
+                    }
+                    _ => "Preview not found",
+                };
+                container(text(message)).center(Fill).into()
+            }
+        };
+
+        column![
+            compare_toolbar(compare, targets),
+            rule::horizontal(1).style(rule::weak),
+            vertical_split(left, right, compare.split_at, |split_at| {
+                Message::Compare(compare::Message::ResizeSplit(split_at))
+            }),
+        ]
+        .into()
+    }
+
+    /// Returns true if the selected preview is shown by itself on the canvas.
+    fn is_single_preview(&self) -> bool {
+        self.selected_index.is_some()
+            && !self.is_matrix
+            && self.viewport.selection == viewport::Selection::Fill
+    }
+
+    /// The canvas toolbar, which is only shown when the selected preview is shown by itself.
+    fn canvas_toolbar(&self) -> Option<Element<'_, Message>> {
+        if !self.is_single_preview() {
+            return None;
+        }
+
+        Some(
+            column![
+                canvas_toolbar(self.canvas_settings(), &self.canvas),
+                rule::horizontal(1).style(rule::weak),
+            ]
+            .into(),
+        )
+    }
+
+    /// The canvas settings of the selected preview.
+    fn canvas_settings(&self) -> canvas::Settings {
+        self.selected_index
+            .and_then(|index| self.descriptors.get(index))
+            .map(|descriptor| self.canvas.settings(&descriptor.metadata().path()))
+            .unwrap_or_default()
+    }
+
+    /// The scale the selected preview was last shown at when zoomed to fit, if it's known.
+    fn fit_scale(&self) -> Option<f32> {
+        self.selected_index
+            .and_then(|index| self.descriptors.get(index))
+            .and_then(|descriptor| self.canvas.fit_scale(&descriptor.metadata().path()))
+    }
+
     /// Renders the main application window.
     fn view_main_window(&self) -> Element<'_, Message> {
         // Build sidebar with preview list
         let sidebar = column![
             text("Previews").size(18),
             search_input(&self.search),
-            preview_list(self.visible_previews(), self.selected_index),
+            preview_list(
+                self.visible_previews(),
+                self.selected_index,
+                &self.collapsed_groups,
+                &self.query(),
+            ),
         ]
         .spacing(10)
         .padding(10);
 
-        let sidebar = container(scrollable(sidebar))
+        let sidebar = container(scrollable(sidebar).id(SIDEBAR_ID))
             .width(Fill)
             .height(Fill)
             .style(|theme: &Theme| container::Style {
@@ -421,22 +1587,29 @@ impl App {
         // Prevent clicks in the main window if there's an active test recording.
         let preview_body = if self.test.is_recording() {
             stack![
-                preview_area(self.current_preview()),
+                self.preview_body(),
                 opaque(space().width(Fill).height(Fill)),
             ]
             .into()
         } else {
-            preview_area(self.current_preview())
+            self.preview_body()
         };
 
         let preview_content = container(
             column![
-                header(&self.theme),
+                header(
+                    &self.theme,
+                    &self.viewport,
+                    self.is_matrix,
+                    self.compare.is_some()
+                ),
                 rule::horizontal(1).style(rule::weak),
+                replay_failures(&self.replay_failures),
+                screenshot_result(self.screenshot_result.as_ref()),
+                self.canvas_toolbar(),
                 horizontal_split(
                     preview_body,
-                    self.selected_index
-                        .and_then(|index| self.descriptors.get(index))
+                    self.configured_descriptor()
                         .map(|descriptor| { config_pane(descriptor, self.config_tab, self) }),
                     self.config_pane_height,
                     Message::ResizeConfigPane,
@@ -482,9 +1655,29 @@ impl App {
             None
         };
 
-        let page: Element<'_, Message> = crate::widget::dialog(page, &self.dialog, delete_config)
-            .on_update(Message::Dialog)
-            .into();
+        let palette_config = if self.dialog.is_visible() {
+            self.palette.as_ref().map(|state| {
+                crate::widget::dialog::Config::new(command_palette(state, self.palette_matches()))
+                    .title("Command Palette")
+                    .width(560)
+            })
+        } else {
+            None
+        };
+
+        let help_config = (self.dialog.is_visible() && self.is_help_shown).then(|| {
+            crate::widget::dialog::Config::new(keybindings_help(&self.keybindings))
+                .title("Keyboard Shortcuts")
+                .width(480)
+        });
+
+        let page: Element<'_, Message> = crate::widget::dialog(
+            page,
+            &self.dialog,
+            delete_config.or(palette_config).or(help_config),
+        )
+        .on_update(Message::Dialog)
+        .into();
 
         if let Some(theme) = self.theme.as_ref() {
             Animation::new(theme, page)
@@ -506,7 +1699,7 @@ impl App {
         };
 
         // Render the preview wrapped with the recorder to capture interactions
-        let preview_content = container(descriptor.preview.view()).center(Fill);
+        let preview_content = container(descriptor.view(&self.decorators)).center(Fill);
 
         if session.is_recording {
             // Wrap with recorder to capture user interactions
@@ -518,13 +1711,49 @@ impl App {
         }
     }
 
-    /// Returns an iterator over the previews that match the current search query.
-    fn visible_previews(&self) -> impl Iterator<Item = (usize, &Descriptor)> {
-        let query = self.search.trim().to_lowercase();
-        self.descriptors
+    /// Renders a window that a preview was popped out into. The preview shares its
+    /// state with the main window, so it's still controlled from the config pane there.
+    ///
+    /// Its messages are tagged with its index, since another preview may be selected.
+    fn view_pop_out(&self, index: usize) -> Element<'_, Message> {
+        match self.descriptors.get(index) {
+            Some(descriptor) => container(
+                descriptor
+                    .view(&self.decorators)
+                    .map(move |message| Message::preview(index, message)),
+            )
+            .center(Fill)
+            .into(),
+            None => text("Preview not found").into(),
+        }
+    }
+
+    /// The current search query, which can filter on the custom fields of any preview.
+    fn query(&self) -> Query {
+        Query::parse(
+            &self.search,
+            self.descriptors.iter().map(Descriptor::metadata),
+        )
+    }
+
+    /// Returns the previews that match the current search query, with the most relevant first.
+    fn visible_previews(&self) -> Vec<(usize, &Descriptor)> {
+        let query = self.query();
+        let mut previews: Vec<_> = self
+            .descriptors
             .iter()
             .enumerate()
-            .filter(move |(_, descriptor)| descriptor.metadata().matches(&query))
+            .filter_map(|(index, descriptor)| {
+                Some((query.score(descriptor.metadata())?, index, descriptor))
+            })
+            .collect();
+
+        // The sort is stable, so previews keep their registered order without a query.
+        previews.sort_by_key(|(score, ..)| std::cmp::Reverse(*score));
+        previews
+            .into_iter()
+            .map(|(_, index, descriptor)| (index, descriptor))
+            .collect()
     }
 
     /// Returns the title for a given window.
@@ -533,6 +1762,12 @@ impl App {
             self.title
                 .clone()
                 .unwrap_or_else(|| "Snowscape Previews".to_owned())
+        } else if let Some(descriptor) = self
+            .pop_outs
+            .get(&window_id)
+            .and_then(|&index| self.descriptors.get(index))
+        {
+            descriptor.metadata().path()
         } else if let Some(session) = &self.test.session {
             format!("Test: {}", session.preview_name)
         } else {
@@ -554,6 +1789,49 @@ impl App {
     }
 }
 
+/// Animates changes of the app's `theme` from its initial value.
+fn animated_theme(theme: Theme) -> Animated<Theme> {
+    Animated::new(
+        theme,
+        Easing::EASE.with_duration(Duration::from_millis(300)),
+    )
+}
+
+/// Tags the messages of a `task` returned by the preview at the `index`,
+/// so they're sent back to it even if another preview is selected by then.
+fn tag_task(index: usize, task: Task<Message>) -> Task<Message> {
+    task.map(move |message| Message::preview(index, message))
+}
+
+/// Opens the `url` with the platform's default handler, e.g. the browser for web links.
+fn open_url(url: &str) {
+    // Only hand off links that a browser or mail client should handle, so a
+    // description can't launch arbitrary files or programs.
+    let scheme = url
+        .split_once(':')
+        .map(|(scheme, _)| scheme.to_ascii_lowercase());
+    if !matches!(scheme.as_deref(), Some("http" | "https" | "mailto")) {
+        eprintln!("Refusing to open '{url}': unsupported scheme");
+        return;
+    }
+
+    // The url is passed as a single argument to a program that opens it
+    // directly, without going through a shell that would interpret it.
+    let mut command = if cfg!(target_os = "windows") {
+        let mut command = std::process::Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+
+    if let Err(e) = command.arg(url).spawn() {
+        eprintln!("Failed to open '{url}': {e}");
+    }
+}
+
 impl std::fmt::Debug for App {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("App")
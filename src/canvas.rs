@@ -0,0 +1,351 @@
+//! Zoom, pan, and background settings for the canvas around the preview area.
+
+use std::{collections::HashMap, fmt::Display};
+
+use iced::{Color, Size, Vector};
+
+/// How much the preview is magnified on the canvas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Zoom {
+    /// The preview is shown at its natural size, or shrunk to fit if it's larger than the canvas.
+    #[default]
+    Fit,
+    /// The preview is scaled to the given percentage.
+    Percent(u16),
+}
+
+impl Zoom {
+    /// The smallest supported zoom percentage.
+    pub const MIN: u16 = 50;
+    /// The largest supported zoom percentage.
+    pub const MAX: u16 = 400;
+
+    /// The zoom levels the user can pick from.
+    pub const LEVELS: [Zoom; 8] = [
+        Zoom::Fit,
+        Zoom::Percent(50),
+        Zoom::Percent(75),
+        Zoom::Percent(100),
+        Zoom::Percent(150),
+        Zoom::Percent(200),
+        Zoom::Percent(300),
+        Zoom::Percent(400),
+    ];
+
+    /// The factor the preview is scaled by, given the `fit_scale` the canvas
+    /// computed for [`Zoom::Fit`].
+    pub fn scale(self, fit_scale: f32) -> f32 {
+        match self {
+            Zoom::Fit => fit_scale,
+            Zoom::Percent(percent) => f32::from(percent) / 100.0,
+        }
+    }
+
+    /// The next larger zoom level, or the same level if it's already the largest.
+    ///
+    /// [`Zoom::Fit`] steps from the percentage of its `fit_scale`.
+    pub fn zoom_in(self, fit_scale: f32) -> Self {
+        let percent = self.scale(fit_scale) * 100.0;
+        Self::percent_levels()
+            .find(|&level| f32::from(level) > percent)
+            .map_or(self, Zoom::Percent)
+    }
+
+    /// The next smaller zoom level, or the same level if it's already the smallest.
+    ///
+    /// [`Zoom::Fit`] steps from the percentage of its `fit_scale`.
+    pub fn zoom_out(self, fit_scale: f32) -> Self {
+        let percent = self.scale(fit_scale) * 100.0;
+        Self::percent_levels()
+            .rev()
+            .find(|&level| f32::from(level) < percent)
+            .map_or(self, Zoom::Percent)
+    }
+
+    /// Parses a zoom level such as `fit`, `150`, or `150%`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("fit") {
+            return Some(Zoom::Fit);
+        }
+
+        let percent = input.trim_end_matches('%').parse::<u16>().ok()?;
+        (Self::MIN..=Self::MAX)
+            .contains(&percent)
+            .then_some(Zoom::Percent(percent))
+    }
+
+    /// The percentages of all zoom levels in ascending order.
+    fn percent_levels() -> impl DoubleEndedIterator<Item = u16> {
+        Self::LEVELS.into_iter().filter_map(|level| match level {
+            Zoom::Fit => None,
+            Zoom::Percent(percent) => Some(percent),
+        })
+    }
+}
+
+impl Display for Zoom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Zoom::Fit => f.write_str("Fit"),
+            Zoom::Percent(percent) => write!(f, "{percent}%"),
+        }
+    }
+}
+
+/// The scale that shrinks a preview of the `content` size so it fits within the `available`
+/// size of the canvas.
+///
+/// Previews are never enlarged to fit. Neither a dimension without a natural size,
+/// e.g. because the preview fills the canvas, nor a canvas without any space shrinks them.
+pub fn fit_scale(content: Size, available: Size) -> f32 {
+    let axis = |content: f32, available: f32| {
+        if content.is_finite() && content > available && available > 0.0 {
+            available / content
+        } else {
+            1.0
+        }
+    };
+
+    axis(content.width, available.width).min(axis(content.height, available.height))
+}
+
+/// What is drawn behind the preview on the canvas.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Background {
+    /// The background color of the current theme.
+    #[default]
+    Theme,
+    /// A checkerboard pattern that makes transparent areas visible.
+    Checkerboard,
+    /// A custom background color.
+    Custom(Color),
+}
+
+impl Background {
+    /// The color used for a custom background until the user enters one.
+    pub const DEFAULT_CUSTOM: Color = Color::from_rgb(0.5, 0.5, 0.5);
+
+    /// Parses a background such as `theme`, `checkerboard`, or a hex color like `#ff00ff`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("theme") {
+            Some(Background::Theme)
+        } else if input.eq_ignore_ascii_case("checkerboard") {
+            Some(Background::Checkerboard)
+        } else {
+            Color::parse(input).map(Background::Custom)
+        }
+    }
+}
+
+impl Display for Background {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Background::Theme => f.write_str("Theme background"),
+            Background::Checkerboard => f.write_str("Checkerboard"),
+            Background::Custom(_) => f.write_str("Custom color"),
+        }
+    }
+}
+
+/// The canvas settings of a single preview.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Settings {
+    /// How much the preview is magnified.
+    pub zoom: Zoom,
+    /// What is drawn behind the preview.
+    pub background: Background,
+    /// How far the preview has been panned from the center of the canvas.
+    pub offset: Vector,
+}
+
+/// Messages that change the canvas settings of the selected preview.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Changes the zoom level.
+    Zoom(Zoom),
+    /// Zooms in by one level.
+    ZoomIn,
+    /// Zooms out by one level.
+    ZoomOut,
+    /// Pans the preview to the given offset from the center of the canvas.
+    Pan(Vector),
+    /// Moves the preview back to the center of the canvas.
+    ResetPan,
+    /// Changes the background.
+    Background(Background),
+    /// Changes the hex color of the custom background.
+    ChangeColor(String),
+    /// Reports the scale the canvas computed to fit the preview.
+    Fitted(f32),
+}
+
+/// The canvas settings of each preview, keyed by the preview's path.
+#[derive(Debug, Default)]
+pub struct State {
+    /// The settings of each preview that has been changed by the user.
+    settings: HashMap<String, Settings>,
+    /// The hex color input for custom backgrounds.
+    pub color_input: String,
+    /// The scale each preview was last shown at when zoomed to fit.
+    fit_scales: HashMap<String, f32>,
+}
+
+impl State {
+    /// The canvas settings of the given `preview`.
+    pub fn settings(&self, preview: &str) -> Settings {
+        self.settings.get(preview).copied().unwrap_or_default()
+    }
+
+    /// The canvas settings of every preview that has been changed by the user.
+    pub fn all_settings(&self) -> &HashMap<String, Settings> {
+        &self.settings
+    }
+
+    /// Replaces the canvas settings of every preview, e.g. when restoring a workspace.
+    pub fn set_all_settings(&mut self, settings: HashMap<String, Settings>) {
+        self.settings = settings;
+    }
+
+    /// The scale the given `preview` was last shown at when zoomed to fit, if it's known.
+    pub fn fit_scale(&self, preview: &str) -> Option<f32> {
+        self.fit_scales.get(preview).copied()
+    }
+
+    /// Returns true if the color input contains a valid color.
+    pub fn is_color_valid(&self) -> bool {
+        Color::parse(&self.color_input).is_some()
+    }
+
+    /// The color of the custom background option for a preview with the given `settings`.
+    pub fn custom_color(&self, settings: &Settings) -> Color {
+        match settings.background {
+            Background::Custom(color) => color,
+            _ => Color::parse(&self.color_input).unwrap_or(Background::DEFAULT_CUSTOM),
+        }
+    }
+
+    /// Updates the canvas settings of the given `preview`.
+    pub fn update(&mut self, preview: &str, message: Message) {
+        if let Message::Fitted(scale) = message {
+            self.fit_scales.insert(preview.to_string(), scale);
+            return;
+        }
+
+        let fit_scale = self.fit_scale(preview).unwrap_or(1.0);
+        let settings = self.settings.entry(preview.to_string()).or_default();
+        match message {
+            Message::Zoom(zoom) => settings.zoom = zoom,
+            Message::ZoomIn => settings.zoom = settings.zoom.zoom_in(fit_scale),
+            Message::ZoomOut => settings.zoom = settings.zoom.zoom_out(fit_scale),
+            Message::Pan(offset) => settings.offset = offset,
+            Message::ResetPan => settings.offset = Vector::ZERO,
+            Message::Background(background) => settings.background = background,
+            Message::ChangeColor(input) => {
+                if let Some(color) = Color::parse(&input) {
+                    settings.background = Background::Custom(color);
+                }
+                self.color_input = input;
+            }
+            Message::Fitted(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Zooming steps through the zoom levels, starting from the scale fit was computed at.
+    #[test]
+    fn zoom_steps_through_levels() {
+        assert_eq!(Zoom::Fit.zoom_in(1.0), Zoom::Percent(150));
+        assert_eq!(Zoom::Fit.zoom_out(1.0), Zoom::Percent(75));
+        assert_eq!(Zoom::Fit.zoom_in(0.6), Zoom::Percent(75));
+        assert_eq!(Zoom::Fit.zoom_out(0.6), Zoom::Percent(50));
+        assert_eq!(Zoom::Fit.zoom_out(0.4), Zoom::Fit);
+        assert_eq!(Zoom::Percent(400).zoom_in(1.0), Zoom::Percent(400));
+        assert_eq!(Zoom::Percent(50).zoom_out(1.0), Zoom::Percent(50));
+        assert_eq!(Zoom::Percent(120).zoom_in(0.6), Zoom::Percent(150));
+    }
+
+    /// Previews only shrink to fit along dimensions that have a natural size.
+    #[test]
+    fn fit_scale_only_shrinks() {
+        let canvas = Size::new(400.0, 300.0);
+        assert_eq!(fit_scale(Size::new(100.0, 50.0), canvas), 1.0);
+        assert_eq!(fit_scale(Size::new(800.0, 150.0), canvas), 0.5);
+        assert_eq!(fit_scale(Size::new(200.0, 1200.0), canvas), 0.25);
+        assert_eq!(fit_scale(Size::new(f32::INFINITY, 600.0), canvas), 0.5);
+    }
+
+    /// Zoom levels can be parsed with or without a percent sign within the supported range.
+    #[test]
+    fn zoom_parse() {
+        assert_eq!(Zoom::parse("fit"), Some(Zoom::Fit));
+        assert_eq!(Zoom::parse("150"), Some(Zoom::Percent(150)));
+        assert_eq!(Zoom::parse("200%"), Some(Zoom::Percent(200)));
+        assert_eq!(Zoom::parse("25"), None);
+        assert_eq!(Zoom::parse("500%"), None);
+        assert_eq!(Zoom::parse("big"), None);
+    }
+
+    /// Backgrounds can be parsed by name or as a hex color.
+    #[test]
+    fn background_parse() {
+        assert_eq!(Background::parse("Theme"), Some(Background::Theme));
+        assert_eq!(
+            Background::parse("checkerboard"),
+            Some(Background::Checkerboard)
+        );
+        assert_eq!(
+            Background::parse("#ff0000"),
+            Some(Background::Custom(Color::from_rgb8(255, 0, 0)))
+        );
+        assert_eq!(Background::parse("plaid"), None);
+    }
+
+    /// Settings are tracked separately for each preview.
+    #[test]
+    fn settings_are_per_preview() {
+        let mut state = State::default();
+        state.update("Button", Message::Zoom(Zoom::Percent(200)));
+        state.update("Card", Message::Background(Background::Checkerboard));
+
+        assert_eq!(state.settings("Button").zoom, Zoom::Percent(200));
+        assert_eq!(state.settings("Button").background, Background::Theme);
+        assert_eq!(state.settings("Card").zoom, Zoom::Fit);
+        assert_eq!(state.settings("Other"), Settings::default());
+    }
+
+    /// Zooming in from fit steps from the scale the canvas last reported.
+    #[test]
+    fn zoom_in_from_reported_fit() {
+        let mut state = State::default();
+        state.update("Button", Message::Fitted(0.6));
+        state.update("Card", Message::Fitted(0.3));
+        state.update("Button", Message::ZoomIn);
+        assert_eq!(state.settings("Button").zoom, Zoom::Percent(75));
+        assert_eq!(state.fit_scale("Card"), Some(0.3));
+        assert_eq!(state.fit_scale("Other"), None);
+    }
+
+    /// Entering a valid color switches to a custom background.
+    #[test]
+    fn change_color_sets_custom_background() {
+        let mut state = State::default();
+        state.update("Button", Message::ChangeColor("#00ff00".to_string()));
+        assert_eq!(
+            state.settings("Button").background,
+            Background::Custom(Color::from_rgb8(0, 255, 0))
+        );
+
+        state.update("Button", Message::ChangeColor("#00fz".to_string()));
+        assert!(!state.is_color_valid());
+        assert_eq!(
+            state.settings("Button").background,
+            Background::Custom(Color::from_rgb8(0, 255, 0))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Compare mode, which shows the selected preview side by side with another preview,
+//! or with an independent instance of itself that has its own state and parameters.
+
+use std::fmt::Display;
+
+use crate::preview::Descriptor;
+
+/// Messages for compare mode.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Starts or stops comparing the selected preview.
+    Toggle,
+    /// Changes the preview shown on the right.
+    SelectTarget(Target),
+    /// Sets whether interactions with one side are also sent to the other side.
+    ToggleSync(bool),
+    /// Selects which side the configuration pane controls.
+    SelectSide(Side),
+    /// Moves the split between the two sides.
+    ResizeSplit(f32),
+    /// A message emitted by the preview on the right.
+    Right(Box<crate::Message>),
+}
+
+/// Wraps a `message` emitted by the preview on the right, so it isn't sent to the selected one.
+pub fn right(message: crate::Message) -> crate::Message {
+    crate::Message::Compare(Message::Right(Box::new(message)))
+}
+
+/// A side of the comparison.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Side {
+    /// The selected preview.
+    #[default]
+    Left,
+    /// The preview it's compared with.
+    Right,
+}
+
+/// The preview shown on the right of the comparison.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Target {
+    /// A separate instance of the selected preview, which follows the selection.
+    #[default]
+    SamePreview,
+    /// The preview at the `index`, shown by its `path`.
+    Preview { index: usize, path: String },
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Target::SamePreview => f.write_str("Same preview"),
+            Target::Preview { path, .. } => f.write_str(path),
+        }
+    }
+}
+
+/// The state of compare mode while it's active.
+#[derive(Debug)]
+pub struct State {
+    /// The preview shown on the right.
+    pub target: Target,
+    /// The independent instance of the preview on the right, along with its index.
+    pub instance: Option<(usize, Descriptor)>,
+    /// The portion of the width that the left side takes up.
+    pub split_at: f32,
+    /// Whether interactions with one side are also sent to the other side.
+    pub is_synced: bool,
+    /// The side that the configuration pane controls.
+    pub side: Side,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            target: Target::default(),
+            instance: None,
+            split_at: 0.5,
+            is_synced: false,
+            side: Side::default(),
+        }
+    }
+}
+
+impl State {
+    /// The index of the preview to show on the right when the `selected` preview is on the left.
+    pub fn target_index(&self, selected: usize) -> usize {
+        match self.target {
+            Target::SamePreview => selected,
+            Target::Preview { index, .. } => index,
+        }
+    }
+
+    /// The instance of the preview on the right if it's been created.
+    pub fn instance_mut(&mut self) -> Option<&mut Descriptor> {
+        self.instance.as_mut().map(|(_, descriptor)| descriptor)
+    }
+}
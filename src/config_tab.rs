@@ -6,6 +6,8 @@ pub enum ConfigTab {
     /// Displays metadata information about the current preview.
     #[default]
     About,
+    /// Shows the source code of the current preview.
+    Code,
     /// Lists dynamic parameters that the user can configure.
     Parameters,
     /// Displays messages the current preview has emitted.
@@ -18,8 +20,9 @@ pub enum ConfigTab {
 
 impl ConfigTab {
     /// All possible configuration tabs.
-    pub const ALL: [ConfigTab; 5] = [
+    pub const ALL: [ConfigTab; 6] = [
         ConfigTab::About,
+        ConfigTab::Code,
         ConfigTab::Parameters,
         ConfigTab::Messages,
         ConfigTab::Performance,
@@ -30,6 +33,7 @@ impl ConfigTab {
     pub fn name(&self) -> &'static str {
         match self {
             ConfigTab::About => "About",
+            ConfigTab::Code => "Code",
             ConfigTab::Parameters => "Parameters",
             ConfigTab::Messages => "Messages",
             ConfigTab::Performance => "Performance",
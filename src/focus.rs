@@ -0,0 +1,51 @@
+//! An operation that moves keyboard focus into a part of the application.
+
+use iced::Rectangle;
+use iced::advanced::widget::{Id, Operation, operation::Focusable};
+
+/// Focuses the first focusable widget within the container with the `target` ID,
+/// unfocusing every other widget.
+pub fn focus_first_within(target: impl Into<Id>) -> impl Operation {
+    FocusFirstWithin {
+        target: target.into(),
+        is_entering: false,
+        is_within: false,
+        has_focused: false,
+    }
+}
+
+struct FocusFirstWithin {
+    /// The ID of the container to focus a widget within.
+    target: Id,
+    /// Whether the target container was just emitted, so its children are traversed next.
+    is_entering: bool,
+    /// Whether the operation is traversing the children of the target container.
+    is_within: bool,
+    /// Whether a widget within the target container has been focused.
+    has_focused: bool,
+}
+
+impl Operation for FocusFirstWithin {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if std::mem::take(&mut self.is_entering) {
+            self.is_within = true;
+            operate(self);
+            self.is_within = false;
+        } else {
+            operate(self);
+        }
+    }
+
+    fn container(&mut self, id: Option<&Id>, _bounds: Rectangle) {
+        self.is_entering = id == Some(&self.target);
+    }
+
+    fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+        if self.is_within && !self.has_focused {
+            state.focus();
+            self.has_focused = true;
+        } else {
+            state.unfocus();
+        }
+    }
+}
@@ -6,6 +6,30 @@ pub fn checkmark<'a>() -> Svg<'a> {
     svg(svg::Handle::from_memory(BYTES))
 }
 
+pub fn chevron_down<'a>() -> Svg<'a> {
+    const BYTES: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/assets/chevron-down.svg"
+    ));
+    svg(svg::Handle::from_memory(BYTES))
+}
+
+pub fn chevron_right<'a>() -> Svg<'a> {
+    const BYTES: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/assets/chevron-right.svg"
+    ));
+    svg(svg::Handle::from_memory(BYTES))
+}
+
+pub fn chevron_up<'a>() -> Svg<'a> {
+    const BYTES: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/assets/chevron-up.svg"
+    ));
+    svg(svg::Handle::from_memory(BYTES))
+}
+
 pub fn circle_slash<'a>() -> Svg<'a> {
     const BYTES: &[u8] = include_bytes!(concat!(
         env!("CARGO_MANIFEST_DIR"),
@@ -14,6 +38,22 @@ pub fn circle_slash<'a>() -> Svg<'a> {
     svg(svg::Handle::from_memory(BYTES))
 }
 
+pub fn external_link<'a>() -> Svg<'a> {
+    const BYTES: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/assets/external-link.svg"
+    ));
+    svg(svg::Handle::from_memory(BYTES))
+}
+
+pub fn move_horizontal<'a>() -> Svg<'a> {
+    const BYTES: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/assets/move-horizontal.svg"
+    ));
+    svg(svg::Handle::from_memory(BYTES))
+}
+
 pub fn play<'a>() -> Svg<'a> {
     const BYTES: &[u8] = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/play.svg"));
     svg(svg::Handle::from_memory(BYTES))
@@ -0,0 +1,434 @@
+//! Keyboard shortcuts that navigate the preview app.
+
+use std::fmt::Display;
+
+use iced::keyboard::{Key, Modifiers, key::Named};
+
+/// An action in the preview app that can be bound to a keyboard shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Selects the next visible preview in the sidebar.
+    SelectNext,
+    /// Selects the previous visible preview in the sidebar.
+    SelectPrevious,
+    /// Selects the first visible preview in the sidebar.
+    SelectFirst,
+    /// Selects the last visible preview in the sidebar.
+    SelectLast,
+    /// Selects the first preview of the next group in the sidebar.
+    NextGroup,
+    /// Selects the first preview of the previous group in the sidebar.
+    PreviousGroup,
+    /// Moves keyboard focus into the selected preview.
+    FocusPreview,
+    /// Focuses the search input.
+    FocusSearch,
+    /// Resets the selected preview to its initial state.
+    ResetPreview,
+    /// Opens or closes the command palette.
+    CommandPalette,
+    /// Shows or hides the list of keyboard shortcuts.
+    ShowHelp,
+}
+
+impl Action {
+    /// All actions in the order they're listed in the help overlay.
+    pub const ALL: [Action; 11] = [
+        Action::SelectNext,
+        Action::SelectPrevious,
+        Action::SelectFirst,
+        Action::SelectLast,
+        Action::NextGroup,
+        Action::PreviousGroup,
+        Action::FocusPreview,
+        Action::FocusSearch,
+        Action::ResetPreview,
+        Action::CommandPalette,
+        Action::ShowHelp,
+    ];
+
+    /// A short description of the action shown in the help overlay.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::SelectNext => "Select the next preview",
+            Action::SelectPrevious => "Select the previous preview",
+            Action::SelectFirst => "Select the first preview",
+            Action::SelectLast => "Select the last preview",
+            Action::NextGroup => "Jump to the next group",
+            Action::PreviousGroup => "Jump to the previous group",
+            Action::FocusPreview => "Focus the selected preview",
+            Action::FocusSearch => "Focus the search input",
+            Action::ResetPreview => "Reset the selected preview",
+            Action::CommandPalette => "Open the command palette",
+            Action::ShowHelp => "Show keyboard shortcuts",
+        }
+    }
+}
+
+/// Named keys that can be used in shortcuts along with the name they're written as.
+const NAMED_KEYS: [(&str, Named); 14] = [
+    ("Up", Named::ArrowUp),
+    ("Down", Named::ArrowDown),
+    ("Left", Named::ArrowLeft),
+    ("Right", Named::ArrowRight),
+    ("Home", Named::Home),
+    ("End", Named::End),
+    ("PageUp", Named::PageUp),
+    ("PageDown", Named::PageDown),
+    ("Enter", Named::Enter),
+    ("Escape", Named::Escape),
+    ("Tab", Named::Tab),
+    ("Space", Named::Space),
+    ("Backspace", Named::Backspace),
+    ("Delete", Named::Delete),
+];
+
+/// Modifiers that turn a character into a chord, rather than changing the character like `Shift`.
+const CHORDS: Modifiers = Modifiers::CTRL.union(Modifiers::ALT).union(Modifiers::LOGO);
+
+/// A key pressed along with any modifiers, e.g. `Ctrl+K` or `j`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    key: Key,
+    modifiers: Modifiers,
+}
+
+impl Shortcut {
+    /// Parses a shortcut such as `Ctrl+K`, `Shift+Down`, `j`, or `?`.
+    ///
+    /// Modifiers are `Ctrl`, `Alt`, `Shift`, `Cmd`, and `Mod`, which is `Cmd` on macOS
+    /// and `Ctrl` elsewhere. Keys are either a single character or a named key like
+    /// `Up`, `Home`, or `Enter`. Letters are case-sensitive unless they're combined with
+    /// `Ctrl`, `Alt`, or `Cmd`, so `G` means `Shift+G` while `Ctrl+K` means `Ctrl+k`.
+    pub fn parse(shortcut: &str) -> Option<Self> {
+        let (modifiers, key) = match shortcut.rsplit_once('+') {
+            // Allow binding the plus key itself, e.g. `Ctrl++`.
+            Some((modifiers, "")) => (modifiers.strip_suffix('+')?, "+"),
+            Some((modifiers, key)) => (modifiers, key),
+            None => ("", shortcut),
+        };
+
+        let modifiers = modifiers
+            .split('+')
+            .filter(|modifier| !modifier.is_empty())
+            .try_fold(Modifiers::empty(), |modifiers, modifier| {
+                let modifier = match modifier.to_lowercase().as_str() {
+                    "ctrl" | "control" => Modifiers::CTRL,
+                    "alt" | "option" => Modifiers::ALT,
+                    "shift" => Modifiers::SHIFT,
+                    "cmd" | "super" | "logo" => Modifiers::LOGO,
+                    "mod" => Modifiers::COMMAND,
+                    _ => return None,
+                };
+                Some(modifiers | modifier)
+            })?;
+
+        let name = key.strip_prefix("Arrow").unwrap_or(key);
+        let key = match NAMED_KEYS
+            .iter()
+            .find(|(key_name, _)| key_name.eq_ignore_ascii_case(name))
+        {
+            Some((_, named)) => Key::Named(*named),
+            None if key.chars().count() == 1 => Key::Character(key.into()),
+            None => return None,
+        };
+
+        Some(Self { key, modifiers })
+    }
+
+    /// Returns true if pressing the `key` with the `modifiers` triggers the shortcut.
+    ///
+    /// Shift is ignored for characters since it already changes the character, e.g. `?`.
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        let (is_same_key, relevant) = match (&self.key, key) {
+            (Key::Character(expected), Key::Character(actual)) => {
+                let is_same = if self.modifiers.intersects(CHORDS) {
+                    expected.to_lowercase() == actual.to_lowercase()
+                } else {
+                    expected == actual
+                };
+                (is_same, CHORDS)
+            }
+            (expected, actual) => (expected == actual, Modifiers::all()),
+        };
+
+        is_same_key && modifiers.intersection(relevant) == self.modifiers.intersection(relevant)
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let logo = if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        };
+
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, logo),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match &self.key {
+            Key::Character(c) if self.modifiers.intersects(CHORDS) => {
+                f.write_str(&c.to_uppercase())
+            }
+            Key::Character(c) => f.write_str(c),
+            Key::Named(named) => {
+                let name = NAMED_KEYS
+                    .iter()
+                    .find(|(_, key)| key == named)
+                    .map_or("?", |(name, _)| name);
+                f.write_str(name)
+            }
+            Key::Unidentified => f.write_str("?"),
+        }
+    }
+}
+
+/// A keyboard shortcut bound to an [`Action`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keybinding {
+    /// The shortcut that triggers the action.
+    pub shortcut: Shortcut,
+    /// The action that is triggered.
+    pub action: Action,
+}
+
+impl Keybinding {
+    /// Binds the `shortcut` to the `action`. See [`Shortcut::parse`] for the format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `shortcut` can't be parsed.
+    pub fn new(shortcut: &str, action: Action) -> Self {
+        let Some(shortcut) = Shortcut::parse(shortcut) else {
+            panic!("Invalid keyboard shortcut '{shortcut}'");
+        };
+        Self { shortcut, action }
+    }
+
+    /// The keybindings that are available by default.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("Down", Action::SelectNext),
+            Self::new("j", Action::SelectNext),
+            Self::new("Up", Action::SelectPrevious),
+            Self::new("k", Action::SelectPrevious),
+            Self::new("Home", Action::SelectFirst),
+            Self::new("End", Action::SelectLast),
+            Self::new("]", Action::NextGroup),
+            Self::new("[", Action::PreviousGroup),
+            Self::new("Enter", Action::FocusPreview),
+            Self::new("/", Action::FocusSearch),
+            Self::new("Mod+R", Action::ResetPreview),
+            Self::new("Mod+K", Action::CommandPalette),
+            Self::new("?", Action::ShowHelp),
+        ]
+    }
+}
+
+/// The action bound to the `key` pressed with the `modifiers`, if any.
+///
+/// Later keybindings take precedence, so custom bindings can be appended to the defaults.
+pub fn action(keybindings: &[Keybinding], key: &Key, modifiers: Modifiers) -> Option<Action> {
+    keybindings
+        .iter()
+        .rev()
+        .find(|binding| binding.shortcut.matches(key, modifiers))
+        .map(|binding| binding.action)
+}
+
+/// The action bound to the `key` pressed with the `modifiers` while the preview area has
+/// keyboard focus, which only includes chords like `Mod+K` so previews receive plain keys.
+pub(crate) fn preview_action(
+    keybindings: &[Keybinding],
+    key: &Key,
+    modifiers: Modifiers,
+) -> Option<Action> {
+    keybindings
+        .iter()
+        .rev()
+        .filter(|binding| binding.shortcut.modifiers.intersects(CHORDS))
+        .find(|binding| binding.shortcut.matches(key, modifiers))
+        .map(|binding| binding.action)
+}
+
+/// The position within the `visible` previews that a navigation `action` selects,
+/// given the index of the `selected` preview and the group path of each visible preview.
+///
+/// Returns `None` if the action doesn't navigate or there is nothing to select.
+pub(crate) fn navigate(
+    action: Action,
+    visible: &[(usize, &str)],
+    selected: Option<usize>,
+) -> Option<usize> {
+    let last = visible.len().checked_sub(1)?;
+    let current =
+        selected.and_then(|selected| visible.iter().position(|&(index, _)| index == selected));
+    let group = |position: usize| visible[position].1;
+
+    Some(match (action, current) {
+        (Action::SelectFirst, _) | (Action::SelectNext | Action::NextGroup, None) => 0,
+        (Action::SelectLast, _) | (Action::SelectPrevious | Action::PreviousGroup, None) => last,
+        (Action::SelectNext, Some(current)) => (current + 1).min(last),
+        (Action::SelectPrevious, Some(current)) => current.saturating_sub(1),
+        (Action::NextGroup, Some(current)) => (current..=last)
+            .find(|&position| group(position) != group(current))
+            .unwrap_or(current),
+        (Action::PreviousGroup, Some(current)) => {
+            // Jump to the start of the group before the one the current preview is in.
+            let start = (0..=current)
+                .rev()
+                .take_while(|&position| group(position) == group(current))
+                .last()
+                .unwrap_or(current);
+            match start.checked_sub(1) {
+                Some(previous) => (0..=previous)
+                    .rev()
+                    .take_while(|&position| group(position) == group(previous))
+                    .last()
+                    .unwrap_or(previous),
+                None => start,
+            }
+        }
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shortcuts are parsed from modifiers followed by a named key or a character.
+    #[test]
+    fn parse_shortcuts() {
+        assert_eq!(
+            Shortcut::parse("Ctrl+Shift+Down"),
+            Some(Shortcut {
+                key: Key::Named(Named::ArrowDown),
+                modifiers: Modifiers::CTRL | Modifiers::SHIFT,
+            })
+        );
+        assert_eq!(
+            Shortcut::parse("ArrowUp"),
+            Some(Shortcut {
+                key: Key::Named(Named::ArrowUp),
+                modifiers: Modifiers::empty(),
+            })
+        );
+        assert_eq!(
+            Shortcut::parse("Ctrl++"),
+            Some(Shortcut {
+                key: Key::Character("+".into()),
+                modifiers: Modifiers::CTRL,
+            })
+        );
+        assert_eq!(Shortcut::parse("Hyper+K"), None);
+        assert_eq!(Shortcut::parse("Ctrl+Unknown"), None);
+    }
+
+    /// Shift is ignored for characters, but not for named keys.
+    #[test]
+    fn shortcut_matches_modifiers() {
+        let palette = Shortcut::parse("Ctrl+K").unwrap();
+        assert!(palette.matches(&Key::Character("k".into()), Modifiers::CTRL));
+        assert!(!palette.matches(&Key::Character("k".into()), Modifiers::empty()));
+
+        let last = Shortcut::parse("G").unwrap();
+        assert!(last.matches(&Key::Character("G".into()), Modifiers::SHIFT));
+        assert!(!last.matches(&Key::Character("g".into()), Modifiers::empty()));
+
+        let help = Shortcut::parse("?").unwrap();
+        assert!(help.matches(&Key::Character("?".into()), Modifiers::SHIFT));
+        assert!(!help.matches(&Key::Character("?".into()), Modifiers::CTRL));
+
+        let down = Shortcut::parse("Down").unwrap();
+        assert!(down.matches(&Key::Named(Named::ArrowDown), Modifiers::empty()));
+        assert!(!down.matches(&Key::Named(Named::ArrowDown), Modifiers::SHIFT));
+    }
+
+    /// Shortcuts are displayed the way they're written in the help overlay.
+    #[test]
+    fn display_shortcuts() {
+        assert_eq!(Shortcut::parse("Ctrl+k").unwrap().to_string(), "Ctrl+K");
+        assert_eq!(Shortcut::parse("Alt+End").unwrap().to_string(), "Alt+End");
+        assert_eq!(Shortcut::parse("j").unwrap().to_string(), "j");
+    }
+
+    /// Bindings added after the defaults take precedence over them.
+    #[test]
+    fn later_bindings_take_precedence() {
+        let mut keybindings = Keybinding::defaults();
+        keybindings.push(Keybinding::new("j", Action::NextGroup));
+
+        let j = Key::Character("j".into());
+        assert_eq!(
+            action(&keybindings, &j, Modifiers::empty()),
+            Some(Action::NextGroup)
+        );
+        assert_eq!(action(&keybindings, &j, Modifiers::CTRL), None);
+    }
+
+    /// Plain keys are left to a focused preview, e.g. one that listens to the arrow keys,
+    /// while chords still trigger their actions.
+    #[test]
+    fn focused_preview_only_handles_chords() {
+        let keybindings = Keybinding::defaults();
+        let down = Key::Named(Named::ArrowDown);
+        assert_eq!(
+            action(&keybindings, &down, Modifiers::empty()),
+            Some(Action::SelectNext)
+        );
+        assert_eq!(
+            preview_action(&keybindings, &down, Modifiers::empty()),
+            None
+        );
+        assert_eq!(
+            preview_action(
+                &keybindings,
+                &Key::Character("j".into()),
+                Modifiers::empty()
+            ),
+            None
+        );
+        assert_eq!(
+            preview_action(
+                &keybindings,
+                &Key::Character("k".into()),
+                Modifiers::COMMAND
+            ),
+            Some(Action::CommandPalette)
+        );
+    }
+
+    /// Previews are selected in the order they're visible, clamped to the ends of the list.
+    #[test]
+    fn navigate_between_previews() {
+        let visible = [(2, "A"), (0, "A"), (1, "")];
+        assert_eq!(navigate(Action::SelectNext, &visible, Some(2)), Some(1));
+        assert_eq!(navigate(Action::SelectNext, &visible, Some(1)), Some(2));
+        assert_eq!(navigate(Action::SelectPrevious, &visible, Some(2)), Some(0));
+        assert_eq!(navigate(Action::SelectLast, &visible, Some(2)), Some(2));
+        assert_eq!(navigate(Action::SelectNext, &visible, None), Some(0));
+        assert_eq!(navigate(Action::SelectNext, &[], Some(0)), None);
+        assert_eq!(navigate(Action::FocusSearch, &visible, Some(0)), None);
+    }
+
+    /// Group jumps select the first preview of the next or previous group.
+    #[test]
+    fn navigate_between_groups() {
+        let visible = [(0, "A"), (1, "A"), (2, "B"), (3, "B"), (4, "")];
+        assert_eq!(navigate(Action::NextGroup, &visible, Some(1)), Some(2));
+        assert_eq!(navigate(Action::NextGroup, &visible, Some(4)), Some(4));
+        assert_eq!(navigate(Action::PreviousGroup, &visible, Some(3)), Some(0));
+        assert_eq!(navigate(Action::PreviousGroup, &visible, Some(4)), Some(2));
+        assert_eq!(navigate(Action::PreviousGroup, &visible, Some(1)), Some(0));
+    }
+}
@@ -1,12 +1,31 @@
+// Lets derived code refer to `::snowscape` within the crate's own tests.
+#[cfg(test)]
+extern crate self as snowscape;
+
 mod app;
+pub mod canvas;
+mod compare;
 mod config_tab;
+mod focus;
 pub mod icon;
+pub mod keybinding;
+mod listing;
 mod message;
 pub mod metadata;
+mod palette;
 pub mod preview;
+#[doc(hidden)]
+pub mod registry;
+#[cfg(feature = "internal")]
+mod reload;
 pub mod screenshot;
+mod search;
+mod source;
 pub mod style;
 pub mod test;
+mod tree;
+pub mod viewport;
+mod workspace;
 
 #[cfg(feature = "internal")]
 pub mod widget;
@@ -22,17 +41,23 @@ pub use message::Message;
 
 pub use app::App;
 
+pub use keybinding::Keybinding;
+
 pub use metadata::Metadata;
 use preview::Preview;
 pub use preview::{dynamic, stateful, stateless};
+pub use snowscape_macros::{ParamEnum, Params, preview};
+pub use viewport::Viewport;
 
 /// Runs the Snowscape preview application.
 ///
 /// Runs the application previews by default, and supports passing command-line arguments
-/// for capturing screenshots of a specific preview.
+/// for launching on a specific preview or capturing screenshots of it.
 ///
 /// ```bash
-/// cargo run -- --screenshot --preview "Button" --output ./screenshot.png
+/// cargo run -- --preview "Card" --param "Title=Hello" --tab Parameters
+/// cargo run -- --screenshot "Button" --output ./screenshot.png
+/// cargo run -- --list > previews.json
 /// ```
 pub fn run<F>(configure: F) -> iced::Result
 where
@@ -49,6 +74,19 @@ where
             eprintln!("Run with --help for usage information.");
             std::process::exit(1);
         }
+        screenshot::ParseResult::List => {
+            let app = (configure)(App::default());
+            match listing::to_json(app.descriptors()) {
+                Ok(json) => {
+                    println!("{json}");
+                    Ok(())
+                }
+                Err(e) => {
+                    eprintln!("Failed to list previews: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
         screenshot::ParseResult::Screenshot(options) => {
             let app = (configure)(App::default());
             match screenshot::capture(&app, &options) {
@@ -62,8 +100,8 @@ where
                 }
             }
         }
-        screenshot::ParseResult::RunGui => iced::daemon(
-            move || App::setup(configure.clone()),
+        screenshot::ParseResult::RunGui(options) => iced::daemon(
+            move || App::setup(configure.clone(), &options),
             App::update,
             App::view,
         )
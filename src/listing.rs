@@ -0,0 +1,116 @@
+//! A machine-readable listing of previews and their metadata, e.g. for
+//! generating documentation or checking ownership in CI.
+//!
+//! ```bash
+//! cargo run -- --list > previews.json
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::preview::Descriptor;
+
+/// A preview in the listing.
+#[derive(Debug, Serialize)]
+struct Entry<'a> {
+    label: &'a str,
+    variant: Option<&'a str>,
+    /// The group path followed by the qualified label, which identifies the preview.
+    path: String,
+    group: Option<&'a str>,
+    description: Option<&'a str>,
+    tags: &'a [String],
+    status: Option<&'static str>,
+    /// The note about the replacement of a deprecated component.
+    replacement: Option<&'a str>,
+    owners: &'a [String],
+    links: Vec<Link<'a>>,
+    fields: BTreeMap<&'a str, &'a str>,
+    /// Where the preview is defined, e.g. `src/main.rs:12`.
+    source: Option<String>,
+}
+
+/// An external link in the listing.
+#[derive(Debug, Serialize)]
+struct Link<'a> {
+    label: &'a str,
+    url: &'a str,
+}
+
+/// Lists the previews of the `descriptors` as pretty-printed JSON.
+pub fn to_json(descriptors: &[Descriptor]) -> serde_json::Result<String> {
+    let entries: Vec<Entry<'_>> = descriptors
+        .iter()
+        .map(|descriptor| {
+            let metadata = descriptor.metadata();
+            Entry {
+                label: &metadata.label,
+                variant: metadata.variant.as_deref(),
+                path: metadata.path(),
+                group: metadata.group.as_deref(),
+                description: metadata.description.as_deref(),
+                tags: &metadata.tags,
+                status: metadata.status.as_ref().map(|status| status.name()),
+                replacement: metadata
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.replacement()),
+                owners: &metadata.owners,
+                links: metadata
+                    .links
+                    .iter()
+                    .map(|link| Link {
+                        label: &link.label,
+                        url: &link.url,
+                    })
+                    .collect(),
+                fields: metadata
+                    .fields
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str()))
+                    .collect(),
+                source: metadata
+                    .location
+                    .map(|location| format!("{}:{}", location.file, location.line)),
+            }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::Status;
+    use crate::stateless;
+
+    /// Every preview is listed with its metadata.
+    #[test]
+    fn lists_metadata_as_json() {
+        let preview = stateless("Button", || -> iced::Element<'static, ()> {
+            iced::widget::space().into()
+        })
+        .group("Inputs")
+        .status(Status::deprecated("Use ActionButton"))
+        .owners(["Design Systems"])
+        .link("Figma", "https://figma.com/button")
+        .field("Since", "0.3");
+
+        let json = to_json(&[Descriptor::new(preview)]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let entry = &value[0];
+        assert_eq!(entry["path"], "Inputs/Button");
+        assert_eq!(entry["status"], "Deprecated");
+        assert_eq!(entry["replacement"], "Use ActionButton");
+        assert_eq!(entry["owners"][0], "Design Systems");
+        assert_eq!(entry["links"][0]["url"], "https://figma.com/button");
+        assert_eq!(entry["fields"]["Since"], "0.3");
+        assert!(
+            entry["source"]
+                .as_str()
+                .is_some_and(|source| source.starts_with("src/listing.rs:"))
+        );
+    }
+}
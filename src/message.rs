@@ -1,9 +1,14 @@
 use std::path::PathBuf;
 use std::{any::Any, fmt::Debug};
 
-use iced::{Theme, theme, window};
+use iced::{Theme, keyboard, theme, time::Instant, window};
 
-use crate::{config_tab::ConfigTab, dynamic, test, widget::dialog};
+use crate::{
+    canvas, compare,
+    config_tab::ConfigTab,
+    dynamic, palette, test, viewport,
+    widget::{config_pane::parameter_pane::NumberDraft, dialog},
+};
 
 /// Supertrait for messages that can be used in the preview system.
 /// - `Any`: Previews support any type of message via downcasting
@@ -43,16 +48,26 @@ pub enum Message {
     Noop,
     /// Focuses the search input.
     FocusInput,
+    /// A key was pressed in the given window without being handled by a widget.
+    KeyPressed(window::Id, keyboard::Key, keyboard::Modifiers),
+    /// Changes whether the preview area has keyboard focus, which leaves plain keys to the preview.
+    FocusPreviewArea(bool),
+    /// Shows or hides the list of keyboard shortcuts.
+    ToggleHelp,
     /// Select a different preview by index.
     SelectPreview(usize),
     /// Resets a stateful preview to its initial state.
     ResetPreview,
     /// Change the search query.
     ChangeSearch(String),
+    /// Expands or collapses the group at the given path in the list of previews.
+    ToggleGroup(String),
     /// Change a dynamic parameter's value at some index.
     ChangeParam(usize, dynamic::Value),
     /// Resets all dynamic parameters for the current preview to their default values.
     ResetParams,
+    /// Keeps the text entered into a number field until it's a valid number.
+    DraftNumber(NumberDraft),
     /// Time travel to a previous state in a stateful preview's timeline by index.
     TimeTravel(u32),
     /// Jump to the latest state in a stateful preview's timeline.
@@ -63,12 +78,43 @@ pub enum Message {
     ResizeConfigPane(f32),
     /// Change the currently selected configuration tab below the preview.
     ChangeConfigTab(ConfigTab),
+    /// Canvas-related messages for the selected preview.
+    Canvas(canvas::Message),
+    /// Viewport-related messages.
+    Viewport(viewport::Message),
+    /// Toggles the theme matrix that renders the preview in multiple themes at once.
+    ToggleMatrix,
+    /// Command palette messages.
+    Palette(palette::Message),
+    /// Saves a screenshot of the selected preview to the screenshots folder.
+    TakeScreenshot,
+    /// A screenshot finished saving to the given path, or failed with the given error.
+    ScreenshotSaved(Result<PathBuf, String>),
+    /// Hides the outcome of the last screenshot.
+    DismissScreenshotResult,
+    /// Opens an `http(s)` or `mailto` link from a preview's description.
+    OpenUrl(String),
+    /// Copies the source code of the selected preview to the clipboard.
+    CopyCode,
+    /// Opens the selected preview in its own window.
+    PopOut,
+    /// Compare mode messages.
+    Compare(compare::Message),
+    /// The code was hot reloaded, so the previews are rebuilt and their state restored.
+    HotReload,
+    /// Hides the previews whose messages couldn't be replayed after a hot reload.
+    DismissReplayFailures,
     /// Updates the current theme.
     UpdateTheme(iced_anim::Event<Theme>),
     /// The theme mode of the system has changed.
     ChangeThemeMode(theme::Mode),
     /// Message from a stateful component (type-erased).
     Component(Box<dyn AnyClone>),
+    /// A message emitted by the preview at the given index, e.g. from a pop-out window,
+    /// which is sent to that preview even if another one is selected.
+    Preview(usize, Box<Message>),
+    /// Saves the workspace if it hasn't changed for a moment.
+    SaveWorkspace(Instant),
     /// A window was closed.
     WindowClosed(window::Id),
     /// Test-related messages.
@@ -86,23 +132,52 @@ impl std::fmt::Debug for Message {
         match self {
             Self::Noop => write!(f, "Noop"),
             Self::FocusInput => write!(f, "FocusInput"),
+            Self::KeyPressed(id, key, modifiers) => f
+                .debug_tuple("KeyPressed")
+                .field(id)
+                .field(key)
+                .field(modifiers)
+                .finish(),
+            Self::FocusPreviewArea(focused) => {
+                f.debug_tuple("FocusPreviewArea").field(focused).finish()
+            }
+            Self::ToggleHelp => write!(f, "ToggleHelp"),
             Self::SelectPreview(arg0) => f.debug_tuple("SelectPreview").field(arg0).finish(),
             Self::ResetPreview => write!(f, "ResetPreview"),
             Self::ChangeSearch(text) => f.debug_tuple("ChangeSearch").field(text).finish(),
+            Self::ToggleGroup(path) => f.debug_tuple("ToggleGroup").field(path).finish(),
             Self::ChangeParam(arg0, arg1) => f
                 .debug_tuple("ChangeParam")
                 .field(arg0)
                 .field(arg1)
                 .finish(),
             Self::ResetParams => write!(f, "ResetParams"),
+            Self::DraftNumber(draft) => f.debug_tuple("DraftNumber").field(draft).finish(),
             Self::TimeTravel(arg0) => f.debug_tuple("TimeTravel").field(arg0).finish(),
             Self::JumpToPresent => write!(f, "JumpToPresent"),
             Self::ResizeSidebar(arg0) => f.debug_tuple("ResizeSidebar").field(arg0).finish(),
             Self::ResizeConfigPane(arg0) => f.debug_tuple("ResizeConfigPane").field(arg0).finish(),
             Self::ChangeConfigTab(arg0) => f.debug_tuple("ChangeConfigTab").field(arg0).finish(),
+            Self::Canvas(msg) => f.debug_tuple("Canvas").field(msg).finish(),
+            Self::Viewport(msg) => f.debug_tuple("Viewport").field(msg).finish(),
+            Self::ToggleMatrix => write!(f, "ToggleMatrix"),
+            Self::Palette(msg) => f.debug_tuple("Palette").field(msg).finish(),
+            Self::TakeScreenshot => write!(f, "TakeScreenshot"),
+            Self::ScreenshotSaved(result) => {
+                f.debug_tuple("ScreenshotSaved").field(result).finish()
+            }
+            Self::DismissScreenshotResult => write!(f, "DismissScreenshotResult"),
+            Self::OpenUrl(url) => f.debug_tuple("OpenUrl").field(url).finish(),
+            Self::CopyCode => write!(f, "CopyCode"),
+            Self::PopOut => write!(f, "PopOut"),
+            Self::Compare(msg) => f.debug_tuple("Compare").field(msg).finish(),
+            Self::HotReload => write!(f, "HotReload"),
+            Self::DismissReplayFailures => write!(f, "DismissReplayFailures"),
             Self::UpdateTheme(event) => write!(f, "UpdateTheme({event:?})"),
             Self::ChangeThemeMode(arg0) => f.debug_tuple("ChangeThemeMode").field(arg0).finish(),
             Self::Component(_) => write!(f, "Component(..)"),
+            Self::Preview(index, msg) => f.debug_tuple("Preview").field(index).field(msg).finish(),
+            Self::SaveWorkspace(now) => f.debug_tuple("SaveWorkspace").field(now).finish(),
             Self::WindowClosed(id) => f.debug_tuple("WindowClosed").field(id).finish(),
             Self::Test(msg) => f.debug_tuple("Test").field(msg).finish(),
             Self::OpenDeleteTestDialog(path) => {
@@ -130,19 +205,43 @@ impl Clone for Message {
             match message {
                 Message::Noop => Message::Noop,
                 Message::FocusInput => Message::FocusInput,
+                Message::KeyPressed(id, key, modifiers) => {
+                    Message::KeyPressed(*id, key.clone(), *modifiers)
+                }
+                Message::FocusPreviewArea(focused) => Message::FocusPreviewArea(*focused),
+                Message::ToggleHelp => Message::ToggleHelp,
                 Message::SelectPreview(i) => Message::SelectPreview(*i),
                 Message::ResetPreview => Message::ResetPreview,
                 Message::ChangeSearch(s) => Message::ChangeSearch(s.clone()),
+                Message::ToggleGroup(path) => Message::ToggleGroup(path.clone()),
                 Message::ChangeParam(i, v) => Message::ChangeParam(*i, v.clone()),
                 Message::ResetParams => Message::ResetParams,
+                Message::DraftNumber(draft) => Message::DraftNumber(draft.clone()),
                 Message::TimeTravel(t) => Message::TimeTravel(*t),
                 Message::JumpToPresent => Message::JumpToPresent,
                 Message::ResizeSidebar(f) => Message::ResizeSidebar(*f),
                 Message::ResizeConfigPane(f) => Message::ResizeConfigPane(*f),
                 Message::ChangeConfigTab(tab) => Message::ChangeConfigTab(*tab),
+                Message::Canvas(msg) => Message::Canvas(msg.clone()),
+                Message::Viewport(msg) => Message::Viewport(msg.clone()),
+                Message::ToggleMatrix => Message::ToggleMatrix,
+                Message::Palette(msg) => Message::Palette(msg.clone()),
+                Message::TakeScreenshot => Message::TakeScreenshot,
+                Message::ScreenshotSaved(result) => Message::ScreenshotSaved(result.clone()),
+                Message::DismissScreenshotResult => Message::DismissScreenshotResult,
+                Message::OpenUrl(url) => Message::OpenUrl(url.clone()),
+                Message::CopyCode => Message::CopyCode,
+                Message::PopOut => Message::PopOut,
+                Message::Compare(msg) => Message::Compare(msg.clone()),
+                Message::HotReload => Message::HotReload,
+                Message::DismissReplayFailures => Message::DismissReplayFailures,
                 Message::UpdateTheme(ev) => Message::UpdateTheme(ev.clone()),
                 Message::ChangeThemeMode(mode) => Message::ChangeThemeMode(*mode),
                 Message::Component(inner) => Message::Component(clone_component_payload(&**inner)),
+                Message::Preview(index, inner) => {
+                    Message::Preview(*index, Box::new(clone_message(inner)))
+                }
+                Message::SaveWorkspace(now) => Message::SaveWorkspace(*now),
                 Message::WindowClosed(id) => Message::WindowClosed(*id),
                 Message::Test(msg) => Message::Test(msg.clone()),
                 Message::OpenDeleteTestDialog(path) => Message::OpenDeleteTestDialog(path.clone()),
@@ -160,6 +259,15 @@ impl Message {
     pub fn component(message: impl AnyClone) -> Self {
         Self::Component(Box::new(message))
     }
+
+    /// Tags a `message` emitted by the preview at the `index`, so it's sent back to that
+    /// preview rather than the selected one. Messages that are already tagged are kept as is.
+    pub fn preview(index: usize, message: Message) -> Self {
+        match message {
+            message @ Message::Preview(..) => message,
+            message => Self::Preview(index, Box::new(message)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::path::Path;
+
 /// Metadata associated with a preview.
 #[derive(Debug, Clone, Default)]
 pub struct Metadata {
@@ -6,9 +9,101 @@ pub struct Metadata {
     /// An optional description of the preview.
     pub description: Option<String>,
     /// A way to categorize related previews together in the UI.
+    /// Slashes nest groups within each other, e.g. `Forms/Inputs`.
     pub group: Option<String>,
     /// Tags associated with the preview for filtering.
     pub tags: Vec<String>,
+    /// The name of the variant if this is a named variant of another preview.
+    pub variant: Option<String>,
+    /// The lifecycle status of the previewed component.
+    pub status: Option<Status>,
+    /// The people or teams that own the previewed component.
+    pub owners: Vec<String>,
+    /// External links related to the component, e.g. its design file or issue.
+    pub links: Vec<Link>,
+    /// Arbitrary key/value fields, e.g. `("Since", "0.3")`.
+    pub fields: Vec<(String, String)>,
+    /// Where the preview is defined, which is used to show its source code.
+    pub location: Option<Location>,
+}
+
+/// The lifecycle status of a previewed component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The component is still being designed and may change.
+    Experimental,
+    /// The component is ready to be used.
+    Stable,
+    /// The component shouldn't be used anymore, with an optional note about its replacement.
+    Deprecated(Option<String>),
+}
+
+impl Status {
+    /// A deprecated status with a note about what replaces the component.
+    pub fn deprecated(replacement: impl Into<String>) -> Self {
+        Status::Deprecated(Some(replacement.into()))
+    }
+
+    /// A display name for the status.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Experimental => "Experimental",
+            Status::Stable => "Stable",
+            Status::Deprecated(_) => "Deprecated",
+        }
+    }
+
+    /// The note about the replacement of a deprecated component.
+    pub fn replacement(&self) -> Option<&str> {
+        match self {
+            Status::Deprecated(replacement) => replacement.as_deref(),
+            Status::Experimental | Status::Stable => None,
+        }
+    }
+}
+
+/// An external link related to a previewed component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The text shown for the link, e.g. `Figma`.
+    pub label: String,
+    /// The URL that the link opens.
+    pub url: String,
+}
+
+impl Link {
+    /// Creates a new link to the `url` shown with the `label`.
+    pub fn new(label: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            url: url.into(),
+        }
+    }
+}
+
+/// A location in the source code of the crate that defines a preview.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    /// The path of the source file, relative to the workspace root.
+    pub file: &'static str,
+    /// The 1-based line number.
+    pub line: u32,
+    /// The 1-based column number.
+    pub column: u32,
+}
+
+impl Location {
+    /// Creates a new location in the `file` at the given `line` and `column`.
+    pub const fn new(file: &'static str, line: u32, column: u32) -> Self {
+        Self { file, line, column }
+    }
+
+    /// The location that the function calling this one was called from.
+    #[track_caller]
+    pub fn caller() -> Self {
+        let location = std::panic::Location::caller();
+        Self::new(location.file(), location.line(), location.column())
+    }
 }
 
 impl Metadata {
@@ -19,15 +114,40 @@ impl Metadata {
             description: None,
             group: None,
             tags: Vec::new(),
+            variant: None,
+            status: None,
+            owners: Vec::new(),
+            links: Vec::new(),
+            fields: Vec::new(),
+            location: None,
         }
     }
 
-    /// Sets the description for the metadata.
+    /// Sets the description for the metadata, which is rendered as Markdown.
     pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
         self
     }
 
+    /// Sets the description to the Markdown contents of the file at `path`.
+    ///
+    /// Relative paths are resolved from the working directory, so prefer paths based on
+    /// `env!("CARGO_MANIFEST_DIR")`, or [`include_str!`] to embed the file at compile time.
+    /// The description is left unchanged if the file can't be read.
+    pub fn description_file(self, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(description) => self.description(description),
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to read description file '{}': {e}",
+                    path.display()
+                );
+                self
+            }
+        }
+    }
+
     /// Sets the group for the metadata.
     pub fn group(mut self, group: impl Into<String>) -> Self {
         self.group = Some(group.into());
@@ -40,31 +160,103 @@ impl Metadata {
         self
     }
 
-    /// Checks if the metadata matches the given search `query`.
-    /// Assumes the `query` is already in lowercase.
-    pub(crate) fn matches(&self, query: &str) -> bool {
-        if self.label.to_lowercase().contains(query) {
-            return true;
-        }
+    /// Sets the lifecycle status of the previewed component.
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
 
-        if let Some(description) = &self.description
-            && description.to_lowercase().contains(query)
-        {
-            return true;
-        }
+    /// Sets the owners of the previewed component.
+    pub fn owners(mut self, owners: Vec<String>) -> Self {
+        self.owners = owners;
+        self
+    }
 
-        if let Some(group) = &self.group
-            && group.to_lowercase().contains(query)
-        {
-            return true;
-        }
+    /// Adds an external link with the given `label` and `url`.
+    pub fn link(mut self, label: impl Into<String>, url: impl Into<String>) -> Self {
+        self.links.push(Link::new(label, url));
+        self
+    }
 
-        for tag in &self.tags {
-            if tag.to_lowercase().contains(query) {
-                return true;
-            }
+    /// Adds a custom field with the given `key` and `value`.
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the location that the preview is defined at.
+    pub fn location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the variant name for the metadata.
+    pub fn variant(mut self, variant: impl Into<String>) -> Self {
+        self.variant = Some(variant.into());
+        self
+    }
+
+    /// The label including the variant name, e.g. `Button/Disabled`.
+    /// This is how variants are referred to by screenshots and tests.
+    pub fn qualified_label(&self) -> Cow<'_, str> {
+        match &self.variant {
+            Some(variant) => Cow::Owned(format!("{}/{}", self.label, variant)),
+            None => Cow::Borrowed(&self.label),
         }
+    }
+
+    /// The segments of the group path, e.g. `["Forms", "Inputs"]` for `Forms/Inputs`.
+    /// Empty segments are skipped, so `Forms//Inputs/` is the same as `Forms/Inputs`.
+    pub fn group_path(&self) -> impl Iterator<Item = &str> {
+        self.group
+            .iter()
+            .flat_map(|group| group.split('/'))
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+    }
+
+    /// The group path followed by the qualified label, e.g. `Forms/Inputs/TextField/Disabled`.
+    pub fn path(&self) -> String {
+        self.group_path()
+            .chain(std::iter::once(self.qualified_label().as_ref()))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualified_label_without_variant() {
+        let metadata = Metadata::new("Button");
+        assert_eq!(metadata.qualified_label(), "Button");
+    }
+
+    #[test]
+    fn qualified_label_with_variant() {
+        let metadata = Metadata::new("Button").variant("Disabled");
+        assert_eq!(metadata.qualified_label(), "Button/Disabled");
+    }
+
+    /// Group paths are split on slashes, ignoring empty segments and whitespace.
+    #[test]
+    fn group_path_skips_empty_segments() {
+        let metadata = Metadata::new("TextField").group(" Forms// Inputs /");
+        assert_eq!(
+            metadata.group_path().collect::<Vec<_>>(),
+            ["Forms", "Inputs"]
+        );
+        assert_eq!(Metadata::new("Button").group_path().count(), 0);
+    }
 
-        false
+    #[test]
+    fn path_includes_group_and_variant() {
+        let metadata = Metadata::new("TextField")
+            .group("Forms/Inputs")
+            .variant("Disabled");
+        assert_eq!(metadata.path(), "Forms/Inputs/TextField/Disabled");
+        assert_eq!(Metadata::new("Button").path(), "Button");
     }
 }
@@ -0,0 +1,181 @@
+//! A command palette that fuzzy finds previews, configuration tabs, themes, and actions.
+
+use std::cmp::Reverse;
+
+use crate::search;
+
+/// The ID of the text input of the command palette, which is focused when it opens.
+pub const INPUT_ID: &str = "command_palette_input";
+
+/// Messages for the command palette.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Opens the command palette, or closes it if it's already open.
+    Toggle,
+    /// Changes the query that filters the commands.
+    ChangeQuery(String),
+    /// Selects the next matching command.
+    SelectNext,
+    /// Selects the previous matching command.
+    SelectPrevious,
+    /// Runs the selected command.
+    Submit,
+    /// Runs the matching command at the given index.
+    Run(usize),
+}
+
+/// The state of an open command palette.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    /// The query that filters the commands.
+    pub query: String,
+    /// The index of the selected command within the matching commands.
+    pub selected: usize,
+}
+
+impl State {
+    /// Moves the selection to the next or previous of `count` matching commands, wrapping around.
+    pub fn select(&mut self, is_next: bool, count: usize) {
+        if count == 0 {
+            self.selected = 0;
+        } else if is_next {
+            self.selected = (self.selected + 1) % count;
+        } else {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+}
+
+/// A command that can be run from the command palette.
+#[derive(Debug, Clone)]
+pub struct Command {
+    /// The kind of command, e.g. `Preview` or `Theme`, which is shown next to its label.
+    pub category: &'static str,
+    /// The label that the query is matched against.
+    pub label: String,
+    /// The message that is dispatched when the command runs.
+    pub message: crate::Message,
+}
+
+impl Command {
+    /// Creates a new command that dispatches the `message` when it runs.
+    pub fn new(category: &'static str, label: impl Into<String>, message: crate::Message) -> Self {
+        Self {
+            category,
+            label: label.into(),
+            message,
+        }
+    }
+}
+
+/// A command that matches the query of the command palette.
+#[derive(Debug, Clone)]
+pub struct Match {
+    /// The matching command.
+    pub command: Command,
+    /// The positions of the characters in the label that match the query.
+    pub highlights: Vec<usize>,
+}
+
+/// The `commands` that match the `query` with the most relevant first.
+///
+/// Every term of the query must fuzzy match either the label or the category of a command,
+/// so `theme dark` finds the dark themes. Matches in the label rank above the category.
+pub fn matches(commands: Vec<Command>, query: &str) -> Vec<Match> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+
+    let mut matches: Vec<(u32, Match)> = commands
+        .into_iter()
+        .filter_map(|command| {
+            let mut score = 0;
+            let mut highlights = Vec::new();
+            for term in &terms {
+                let (term_score, positions) =
+                    search::fuzzy_match(term, &command.label).or_else(|| {
+                        search::fuzzy_match(term, command.category)
+                            .map(|(score, _)| (score / 2, Vec::new()))
+                    })?;
+                score += term_score;
+                highlights.extend(positions);
+            }
+
+            highlights.sort_unstable();
+            highlights.dedup();
+            Some((
+                score,
+                Match {
+                    command,
+                    highlights,
+                },
+            ))
+        })
+        .collect();
+
+    // The sort is stable, so commands keep their order without a query.
+    matches.sort_by_key(|(score, _)| Reverse(*score));
+    matches.into_iter().map(|(_, m)| m).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands() -> Vec<Command> {
+        vec![
+            Command::new(
+                "Preview",
+                "Forms/TextField",
+                crate::Message::SelectPreview(0),
+            ),
+            Command::new("Preview", "Button", crate::Message::SelectPreview(1)),
+            Command::new("Theme", "Dark", crate::Message::Noop),
+            Command::new("Theme", "Light", crate::Message::Noop),
+            Command::new("Action", "Reset params", crate::Message::ResetParams),
+        ]
+    }
+
+    fn labels(matches: &[Match]) -> Vec<&str> {
+        matches.iter().map(|m| m.command.label.as_str()).collect()
+    }
+
+    /// Every command matches an empty query in its original order.
+    #[test]
+    fn empty_query_matches_everything() {
+        let found = matches(commands(), "  ");
+        assert_eq!(
+            labels(&found),
+            ["Forms/TextField", "Button", "Dark", "Light", "Reset params"]
+        );
+    }
+
+    /// Commands are fuzzy matched by their label, with the best match first.
+    #[test]
+    fn fuzzy_matches_labels() {
+        let found = matches(commands(), "txt");
+        assert_eq!(labels(&found), ["Forms/TextField"]);
+        assert_eq!(found[0].highlights, [6, 8, 9]);
+
+        let found = matches(commands(), "reset");
+        assert_eq!(labels(&found), ["Reset params"]);
+    }
+
+    /// Terms can match the category of a command, but don't highlight its label.
+    #[test]
+    fn terms_match_categories() {
+        let found = matches(commands(), "theme dark");
+        assert_eq!(labels(&found), ["Dark"]);
+        assert_eq!(found[0].highlights, [0, 1, 2, 3]);
+    }
+
+    /// The selection wraps around the matching commands.
+    #[test]
+    fn select_wraps_around() {
+        let mut state = State::default();
+        state.select(false, 3);
+        assert_eq!(state.selected, 2);
+        state.select(true, 3);
+        assert_eq!(state.selected, 0);
+        state.select(true, 0);
+        assert_eq!(state.selected, 0);
+    }
+}
@@ -1,3 +1,62 @@
+// Builder methods that edit the metadata of each kind of preview.
+macro_rules! metadata_builder_methods {
+    () => {
+        /// Add a description to the preview.
+        pub fn description(mut self, description: impl Into<String>) -> Self {
+            self.metadata = self.metadata.description(description);
+            self
+        }
+
+        /// Add a description to the preview from the Markdown file at `path`.
+        pub fn description_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+            self.metadata = self.metadata.description_file(path);
+            self
+        }
+
+        /// Add a group to the preview.
+        pub fn group(mut self, group: impl Into<String>) -> Self {
+            self.metadata = self.metadata.group(group);
+            self
+        }
+
+        /// Add tags to the preview.
+        pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+            self.metadata = self
+                .metadata
+                .tags(tags.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Set the lifecycle status of the previewed component.
+        pub fn status(mut self, status: crate::metadata::Status) -> Self {
+            self.metadata = self.metadata.status(status);
+            self
+        }
+
+        /// Set the owners of the previewed component.
+        pub fn owners(mut self, owners: impl IntoIterator<Item = impl Into<String>>) -> Self {
+            self.metadata = self
+                .metadata
+                .owners(owners.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Add an external link, e.g. to the component's design file or issue.
+        pub fn link(mut self, label: impl Into<String>, url: impl Into<String>) -> Self {
+            self.metadata = self.metadata.link(label, url);
+            self
+        }
+
+        /// Add a custom field to the preview's metadata.
+        pub fn field(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+            self.metadata = self.metadata.field(key, value);
+            self
+        }
+    };
+}
+
+mod boot;
+mod decorator;
 mod descriptor;
 pub mod dynamic;
 mod history;
@@ -9,12 +68,15 @@ pub mod performance;
 mod stateful;
 mod stateless;
 mod timeline;
+mod variant;
 
 use crate::{Message, preview::dynamic::Param};
-use iced::{Element, Task};
+use iced::{Element, Subscription, Task};
 
+pub use boot::{BootFn, IntoBoot};
+pub use decorator::Decorator;
 pub(crate) use descriptor::Descriptor;
-pub use history::History;
+pub use history::{History, Replay, ReplayError};
 #[cfg(not(feature = "internal"))]
 use performance::Performance;
 #[cfg(feature = "internal")]
@@ -23,6 +85,10 @@ pub use performance::Performance;
 pub use stateful::{Stateful, stateful};
 pub use stateless::{Stateless, stateless, stateless_with};
 pub use timeline::Timeline;
+pub(crate) use variant::Variant;
+
+/// A function that returns the subscriptions of a stateful preview's state.
+pub(crate) type SubscriptionFn<State, Message> = fn(&State) -> Subscription<Message>;
 
 /// Trait for preview components that can be displayed in the preview window.
 ///
@@ -32,12 +98,20 @@ pub trait Preview: Send {
     /// Metadata associated with the preview.
     fn metadata(&self) -> &crate::Metadata;
 
+    /// Mutable access to the metadata associated with the preview.
+    fn metadata_mut(&mut self) -> &mut crate::Metadata;
+
     /// Update the preview state with a message.
     fn update(&mut self, message: Message) -> Task<Message>;
 
     /// Render the preview.
     fn view(&self) -> Element<'_, Message>;
 
+    /// The subscriptions the preview listens to while it is selected.
+    fn subscription(&self) -> Subscription<Message> {
+        Subscription::none()
+    }
+
     /// The total number of messages the preview has emitted.
     fn message_count(&self) -> usize;
 
@@ -50,6 +124,26 @@ pub trait Preview: Send {
         None
     }
 
+    /// Takes the task returned when the preview was booted, if it hasn't run yet.
+    /// This is called whenever the preview is selected.
+    fn take_boot_task(&mut self) -> Task<Message> {
+        Task::none()
+    }
+
+    /// Copies the messages the preview has emitted, so they can be replayed by the
+    /// preview that replaces it after a hot reload.
+    fn to_replay(&self) -> Option<Replay> {
+        None
+    }
+
+    /// Replays the messages of the preview this one replaced after a hot reload.
+    fn replay(&mut self, replay: Replay) -> Result<(), ReplayError> {
+        match replay.messages.len() {
+            0 => Ok(()),
+            total => Err(ReplayError { index: 0, total }),
+        }
+    }
+
     /// The parameters for the dynamic preview if applicable.
     fn params(&self) -> &[Param] {
         &[]
@@ -59,4 +153,20 @@ pub trait Preview: Send {
     fn performance(&self) -> Option<&Performance> {
         None
     }
+
+    /// Takes the named variants of the preview so they can be registered alongside it.
+    ///
+    /// Variants share the preview's metadata and functions apart from the ones given to
+    /// their `variant` method, e.g. a different boot function or default parameters.
+    /// They're listed underneath the preview and referred to as `Label/Variant` by
+    /// screenshots and tests.
+    fn take_variants(&mut self) -> Vec<Box<dyn Preview>> {
+        Vec::new()
+    }
+
+    /// Takes the decorators that wrap only this preview's element, e.g. to apply padding
+    /// or a theme. Decorators also wrap the elements of the preview's variants.
+    fn take_decorators(&mut self) -> Vec<Decorator> {
+        Vec::new()
+    }
 }
@@ -0,0 +1,38 @@
+use iced::Task;
+
+/// A function that boots the state of a stateful preview, optionally along with
+/// a [`Task`] that runs once the preview is selected, e.g. to load initial data.
+///
+/// This is implemented for any function returning either `State` or `(State, Task<Message>)`.
+pub trait BootFn<State, Message>: Send {
+    /// Boots the state and its initial task.
+    fn boot(&self) -> (State, Task<Message>);
+}
+
+impl<T, C, State, Message> BootFn<State, Message> for T
+where
+    T: Fn() -> C + Send,
+    C: IntoBoot<State, Message>,
+{
+    fn boot(&self) -> (State, Task<Message>) {
+        self().into_boot()
+    }
+}
+
+/// The return value of a [`BootFn`] that can be converted into a state and its initial task.
+pub trait IntoBoot<State, Message> {
+    /// Converts the value into a state and its initial task.
+    fn into_boot(self) -> (State, Task<Message>);
+}
+
+impl<State, Message> IntoBoot<State, Message> for State {
+    fn into_boot(self) -> (State, Task<Message>) {
+        (self, Task::none())
+    }
+}
+
+impl<State, Message> IntoBoot<State, Message> for (State, Task<Message>) {
+    fn into_boot(self) -> (State, Task<Message>) {
+        self
+    }
+}
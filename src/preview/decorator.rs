@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use iced::Element;
+
+use crate::Message;
+
+/// A function that wraps the element of a preview, e.g. to apply a theme, padding,
+/// a background, or some other context the preview needs to render properly.
+pub type Decorator =
+    Arc<dyn for<'a> Fn(Element<'a, Message>) -> Element<'a, Message> + Send + Sync>;
+
+/// Wraps the `element` with each of the `decorators` in order, so the last
+/// decorator becomes the outermost wrapper.
+pub(crate) fn decorate<'a, 'b>(
+    element: Element<'a, Message>,
+    decorators: impl IntoIterator<Item = &'b Decorator>,
+) -> Element<'a, Message> {
+    decorators
+        .into_iter()
+        .fold(element, |element, decorator| decorator(element))
+}
@@ -1,24 +1,87 @@
 use std::fmt::Debug;
+use std::sync::Arc;
 
-use crate::{Metadata, Preview};
+use iced::Element;
+use iced::widget::markdown;
+
+use crate::{
+    Message, Metadata, Preview,
+    preview::{Decorator, decorator},
+};
+
+/// Builds a fresh instance of a single preview, or `None` if it no longer exists.
+pub type Factory = Arc<dyn Fn() -> Option<Box<dyn Preview>> + Send + Sync>;
 
 /// A descriptor for a preview component that can be registered.
 pub struct Descriptor {
     pub preview: Box<dyn Preview>,
+    /// Decorators that wrap only this preview's element.
+    pub decorators: Vec<Decorator>,
+    /// Builds a fresh instance of the preview, if it was added with one.
+    pub factory: Option<Factory>,
+    /// The Markdown items of the preview's description, parsed once up front.
+    pub description: Vec<markdown::Item>,
 }
 
 impl Descriptor {
     /// Create a new [`Descriptor`] with the given label and preview.
     pub fn new(preview: impl Preview + 'static) -> Self {
+        Self::from_boxed(Box::new(preview))
+    }
+
+    /// Create a new [`Descriptor`] from an already boxed preview.
+    pub fn from_boxed(preview: Box<dyn Preview>) -> Self {
+        let description = preview
+            .metadata()
+            .description
+            .as_deref()
+            .map(|description| markdown::parse(description).collect())
+            .unwrap_or_default();
+
         Self {
-            preview: Box::new(preview),
+            preview,
+            decorators: Vec::new(),
+            factory: None,
+            description,
         }
     }
 
+    /// Sets the decorators that wrap only this preview's element.
+    pub fn with_decorators(mut self, decorators: Vec<Decorator>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
+    /// Sets the factory that builds a fresh instance of the preview.
+    pub fn with_factory(mut self, factory: Factory) -> Self {
+        self.factory = Some(factory);
+        self
+    }
+
+    /// Builds a fresh instance of the preview with the same decorators, or `None`
+    /// if the preview wasn't added with a factory.
+    pub fn instantiate(&self) -> Option<Self> {
+        let factory = self.factory.clone()?;
+        let preview = factory()?;
+        Some(
+            Self::from_boxed(preview)
+                .with_decorators(self.decorators.clone())
+                .with_factory(factory),
+        )
+    }
+
     /// Get the metadata associated with the preview.
     pub fn metadata(&self) -> &Metadata {
         self.preview.metadata()
     }
+
+    /// Render the preview wrapped in its own decorators, followed by the app-wide `decorators`.
+    pub fn view<'a>(&'a self, decorators: &'a [Decorator]) -> Element<'a, Message> {
+        decorator::decorate(
+            self.preview.view(),
+            self.decorators.iter().chain(decorators),
+        )
+    }
 }
 
 impl Debug for Descriptor {
@@ -1,3 +1,5 @@
+pub(crate) mod appearance;
+mod derive;
 mod extract_params;
 pub mod param;
 pub mod stateful;
@@ -5,9 +7,13 @@ pub mod stateless;
 
 use std::ops::RangeInclusive;
 
+pub use derive::{DefaultParam, Params, params};
 pub use extract_params::ExtractParams;
-use iced::Color;
-pub use param::{Param, boolean, color, number, select, slider, text};
+use iced::{Color, Font, Length, Padding, Theme, alignment::Horizontal};
+pub use param::{
+    Param, ParamEnum, alignment, boolean, color, enumeration, float, font, length, list, number,
+    optional, padding, select, slider, text, theme,
+};
 pub use stateful::stateful;
 pub use stateless::{stateless, stateless_with};
 
@@ -20,10 +26,343 @@ pub enum Value {
     Text(String),
     /// A 32-bit integer value.
     I32(i32),
+    /// A floating-point number with its range, step, and unit.
+    Float(Float),
     /// A selection from a list of options. Stores (selected_index, options).
     Select(usize, Vec<String>),
     /// A slider value with range. Stores (current, range).
     Slider(f32, RangeInclusive<f32>),
     /// A color value.
     Color(Color),
+    /// The length of a widget, e.g. `Fill` or `Fixed(100.0)`. Stores (length, pixels),
+    /// where the pixels are the text entered for a `Fixed` length.
+    Length(Length, Float),
+    /// The padding of a widget with the pixels entered for each side, going clockwise from the top.
+    Padding(Box<[Float; 4]>),
+    /// A horizontal alignment.
+    Alignment(Horizontal),
+    /// A font with an adjustable family, weight, and style.
+    Font(Font),
+    /// One of the built-in themes.
+    Theme(Theme),
+    /// An optional value that's only used while it's enabled. Stores (enabled, param).
+    Optional(bool, Box<Param>),
+    /// A list of items, where new items are copies of the item parameter. Stores (items, item).
+    List(Vec<Param>, Box<Param>),
+}
+
+impl Value {
+    /// Parses the `input` as a value of the same kind, e.g. from a command-line argument.
+    ///
+    /// Booleans accept `true`/`false`, selections accept the name of an option,
+    /// sliders are clamped to their range, floats must be within their range and may end
+    /// with their unit, and colors accept hex colors like `#ff00ff`.
+    /// Lengths accept `fill`, `shrink`, `portion 2`, or `100px`, paddings accept one, two,
+    /// or four sizes like CSS, fonts accept words like `monospace bold italic`, and
+    /// alignments and themes accept their names.
+    /// Optional values accept `none` or a value of their parameter, and lists accept
+    /// the values of their items separated by commas.
+    /// Returns `None` if the `input` isn't valid for this kind of value.
+    pub fn parse(&self, input: &str) -> Option<Value> {
+        let trimmed = input.trim();
+        Some(match self {
+            Value::Bool(_) => match trimmed.to_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => Value::Bool(true),
+                "false" | "no" | "off" | "0" => Value::Bool(false),
+                _ => return None,
+            },
+            Value::Text(_) => Value::Text(input.to_owned()),
+            Value::I32(_) => Value::I32(trimmed.parse().ok()?),
+            Value::Float(float) => Value::Float(float.with_value(float.parse(trimmed)?)),
+            Value::Select(_, options) => {
+                let index = options
+                    .iter()
+                    .position(|option| option.eq_ignore_ascii_case(trimmed))?;
+                Value::Select(index, options.clone())
+            }
+            Value::Slider(_, range) => {
+                let value: f32 = trimmed.parse().ok()?;
+                Value::Slider(value.clamp(*range.start(), *range.end()), range.clone())
+            }
+            Value::Color(_) => Value::Color(Color::parse(trimmed)?),
+            Value::Length(_, pixels) => length_value(appearance::parse_length(trimmed)?, pixels),
+            Value::Padding(_) => Value::Padding(padding_sides(appearance::parse_padding(trimmed)?)),
+            Value::Alignment(_) => Value::Alignment(appearance::parse_alignment(trimmed)?),
+            Value::Font(font) => Value::Font(appearance::parse_font(trimmed, *font)?),
+            Value::Theme(_) => Value::Theme(appearance::parse_theme(trimmed)?),
+            Value::Optional(_, param) if trimmed.eq_ignore_ascii_case("none") => {
+                Value::Optional(false, param.clone())
+            }
+            Value::Optional(_, param) => {
+                Value::Optional(true, Box::new(param.with_value(param.value.parse(input)?)))
+            }
+            Value::List(_, item) => {
+                let items = trimmed
+                    .split(',')
+                    .filter(|input| !input.trim().is_empty())
+                    .map(|input| Some(item.with_value(item.value.parse(input.trim())?)))
+                    .collect::<Option<_>>()?;
+                Value::List(items, item.clone())
+            }
+        })
+    }
+}
+
+/// A floating-point number along with the text entered for it, which may not be valid yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Float {
+    /// The current value, which is always within the `range`.
+    pub value: f64,
+    /// The text entered for the value.
+    pub input: String,
+    /// The range that the value must be within, if any.
+    pub range: Option<RangeInclusive<f64>>,
+    /// The amount that the value changes by when it's incremented or decremented.
+    pub step: f64,
+    /// The unit shown after the value, e.g. `px` or `%`.
+    pub unit: Option<String>,
+}
+
+impl Float {
+    /// The maximum number of decimals shown for a value.
+    const MAX_DECIMALS: usize = 6;
+
+    /// Creates a number of pixels that can't be negative and steps by whole pixels,
+    /// e.g. for a `Fixed` length or the side of a padding.
+    pub fn pixels(value: f32) -> Self {
+        Self {
+            range: Some(0.0..=f64::INFINITY),
+            step: 1.0,
+            unit: Some(String::from("px")),
+            ..Self::new(0.0)
+        }
+        .with_value(f64::from(value))
+    }
+
+    /// Creates a number with the given `value`, no range or unit, and a step of `0.1`.
+    pub fn new(value: f64) -> Self {
+        let mut float = Self {
+            value,
+            input: String::new(),
+            range: None,
+            step: 0.1,
+            unit: None,
+        };
+        float.input = float.format(value);
+        float
+    }
+
+    /// Changes the value, clamping it to the range and rounding away any floating-point error.
+    pub fn with_value(&self, value: f64) -> Self {
+        let factor = 10f64.powi(Self::MAX_DECIMALS as i32);
+        let value = self.clamp((value * factor).round() / factor);
+        Self {
+            value,
+            input: self.format(value),
+            ..self.clone()
+        }
+    }
+
+    /// Changes the entered text, which also changes the value if the `input` is valid.
+    pub fn with_input(&self, input: String) -> Self {
+        Self {
+            value: self.parse(&input).unwrap_or(self.value),
+            input,
+            ..self.clone()
+        }
+    }
+
+    /// Changes the value by the given number of `steps`, which are negative to decrement it.
+    pub fn stepped(&self, steps: i32) -> Self {
+        self.with_value(self.value + f64::from(steps) * self.step)
+    }
+
+    /// Parses the `input` as a value within the range, which may end with the unit.
+    pub fn parse(&self, input: &str) -> Option<f64> {
+        let input = input.trim();
+        let input = self
+            .unit
+            .as_deref()
+            .and_then(|unit| input.strip_suffix(unit))
+            .unwrap_or(input);
+        let value: f64 = input.trim().parse().ok()?;
+        let is_in_range = self
+            .range
+            .as_ref()
+            .is_none_or(|range| range.contains(&value));
+        (value.is_finite() && is_in_range).then_some(value)
+    }
+
+    /// Returns true if the entered text is a valid value.
+    pub fn is_valid(&self) -> bool {
+        self.parse(&self.input).is_some()
+    }
+
+    /// Formats the `value` with at least as many decimals as the step, e.g. `0.50` for a step of `0.05`.
+    pub fn format(&self, value: f64) -> String {
+        let decimals = Self::decimals(self.step).max(Self::decimals(value));
+        format!("{value:.decimals$}")
+    }
+
+    /// The number of decimals needed to show the `value`, e.g. 2 for `0.05`.
+    fn decimals(value: f64) -> usize {
+        let mut value = value.abs();
+        let mut decimals = 0;
+        while decimals < Self::MAX_DECIMALS && (value - value.round()).abs() > 1e-6 {
+            value *= 10.0;
+            decimals += 1;
+        }
+        decimals
+    }
+
+    /// Clamps the `value` to the range, if there is one.
+    fn clamp(&self, value: f64) -> f64 {
+        match &self.range {
+            Some(range) => value.clamp(*range.start(), *range.end()),
+            None => value,
+        }
+    }
+}
+
+/// A length value whose `pixels` follow the `length` while it's `Fixed`, and otherwise
+/// keep the pixels a `Fixed` length starts from.
+pub(crate) fn length_value(length: Length, pixels: &Float) -> Value {
+    let pixels = match length {
+        Length::Fixed(fixed) => pixels.with_value(f64::from(fixed)),
+        _ => pixels.clone(),
+    };
+    Value::Length(length, pixels)
+}
+
+/// The pixels of each side of a `padding`, going clockwise from the top.
+pub(crate) fn padding_sides(padding: Padding) -> Box<[Float; 4]> {
+    Box::new([padding.top, padding.right, padding.bottom, padding.left].map(Float::pixels))
+}
+
+/// The padding with the pixels of each of the `sides`, going clockwise from the top.
+pub(crate) fn padding_from_sides(sides: &[Float; 4]) -> Padding {
+    let [top, right, bottom, left] = sides.each_ref().map(|side| side.value as f32);
+    Padding {
+        top,
+        right,
+        bottom,
+        left,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inputs are parsed as the same kind of value.
+    #[test]
+    fn parse_values() {
+        assert_eq!(Value::Bool(false).parse("True"), Some(Value::Bool(true)));
+        assert_eq!(Value::Bool(false).parse("maybe"), None);
+        assert_eq!(
+            Value::Text(String::new()).parse(" Hello "),
+            Some(Value::Text(String::from(" Hello ")))
+        );
+        assert_eq!(Value::I32(0).parse("-4"), Some(Value::I32(-4)));
+        assert_eq!(
+            Value::Color(Color::BLACK).parse("#ff0000"),
+            Some(Value::Color(Color::from_rgb(1.0, 0.0, 0.0)))
+        );
+    }
+
+    /// Selections are parsed by option name and sliders are clamped to their range.
+    #[test]
+    fn parse_select_and_slider() {
+        let options = vec![String::from("Small"), String::from("Large")];
+        assert_eq!(
+            Value::Select(0, options.clone()).parse("large"),
+            Some(Value::Select(1, options.clone()))
+        );
+        assert_eq!(Value::Select(0, options).parse("Medium"), None);
+        assert_eq!(
+            Value::Slider(0.0, 0.0..=10.0).parse("25"),
+            Some(Value::Slider(10.0, 0.0..=10.0))
+        );
+    }
+
+    /// Optional values are disabled by `none` and lists are separated by commas.
+    #[test]
+    fn parse_optional_and_list() {
+        let count = Param::new("Count", Value::I32(0));
+        assert_eq!(
+            Value::Optional(true, Box::new(count.clone())).parse("None"),
+            Some(Value::Optional(false, Box::new(count.clone())))
+        );
+        assert_eq!(
+            Value::Optional(false, Box::new(count.clone())).parse("4"),
+            Some(Value::Optional(
+                true,
+                Box::new(count.with_value(Value::I32(4)))
+            ))
+        );
+        assert_eq!(
+            Value::List(Vec::new(), Box::new(count.clone())).parse("1, 2,"),
+            Some(Value::List(
+                vec![
+                    count.with_value(Value::I32(1)),
+                    count.with_value(Value::I32(2))
+                ],
+                Box::new(count.clone())
+            ))
+        );
+        assert_eq!(Value::List(Vec::new(), Box::new(count)).parse("1, x"), None);
+    }
+
+    /// Floats are validated against their range, rounded to their step, and accept their unit.
+    #[test]
+    fn float_input_and_steps() {
+        let float = Float {
+            range: Some(0.0..=1.0),
+            step: 0.05,
+            unit: Some(String::from("%")),
+            ..Float::new(0.5)
+        }
+        .with_value(0.5);
+        assert_eq!(float.input, "0.50");
+
+        let typed = float.with_input(String::from("0.75 %"));
+        assert_eq!(typed.value, 0.75);
+        assert!(typed.is_valid());
+
+        let invalid = typed.with_input(String::from("1.5"));
+        assert_eq!(invalid.value, 0.75);
+        assert!(!invalid.is_valid());
+
+        assert_eq!(float.stepped(3).value, 0.65);
+        assert_eq!(float.stepped(-20).value, 0.0);
+        assert_eq!(float.stepped(-20).input, "0.00");
+        assert_eq!(
+            Value::Float(float.clone()).parse("2"),
+            None,
+            "values outside of the range are invalid"
+        );
+    }
+
+    /// Fixed lengths and the sides of paddings keep the text entered for their pixels.
+    #[test]
+    fn pixels_keep_their_input() {
+        let pixels = Float::pixels(100.0);
+        assert_eq!(pixels.input, "100");
+        assert!(!pixels.with_input(String::from("-4")).is_valid());
+        assert_eq!(pixels.with_input(String::from("12px")).value, 12.0);
+
+        let length = Value::Length(Length::Fill, pixels.clone());
+        assert_eq!(
+            length.parse("24px"),
+            Some(Value::Length(Length::Fixed(24.0), Float::pixels(24.0)))
+        );
+        assert_eq!(
+            length.parse("shrink"),
+            Some(Value::Length(Length::Shrink, pixels))
+        );
+
+        let sides = padding_sides(Padding::new(4.0).left(12.0));
+        assert_eq!(sides[3].input, "12");
+        assert_eq!(padding_from_sides(&sides), Padding::new(4.0).left(12.0));
+    }
 }
@@ -0,0 +1,278 @@
+//! Labels and text formats of the iced types that dynamic parameters can adjust.
+//!
+//! The text formats are used to parse command-line arguments and to save parameters
+//! in the workspace state. Lengths parse back to themselves after being formatted,
+//! but the named family of a font is left out since it can't be parsed.
+
+use iced::{
+    Length, Padding, Theme,
+    alignment::Horizontal,
+    font::{self, Family, Font, Weight},
+};
+
+/// The font families that can be chosen, besides the named family of a default font.
+pub const FAMILIES: [Family; 5] = [
+    Family::SansSerif,
+    Family::Serif,
+    Family::Monospace,
+    Family::Cursive,
+    Family::Fantasy,
+];
+
+/// The font weights that can be chosen, from thinnest to boldest.
+pub const WEIGHTS: [Weight; 9] = [
+    Weight::Thin,
+    Weight::ExtraLight,
+    Weight::Light,
+    Weight::Normal,
+    Weight::Medium,
+    Weight::Semibold,
+    Weight::Bold,
+    Weight::ExtraBold,
+    Weight::Black,
+];
+
+/// The font styles that can be chosen.
+pub const STYLES: [font::Style; 3] = [
+    font::Style::Normal,
+    font::Style::Italic,
+    font::Style::Oblique,
+];
+
+/// The horizontal alignments that can be chosen.
+pub const ALIGNMENTS: [Horizontal; 3] = [Horizontal::Left, Horizontal::Center, Horizontal::Right];
+
+/// The label of a font family, e.g. "Sans serif" or the name of a named family.
+pub fn family_label(family: &Family) -> String {
+    String::from(match family {
+        Family::Name(name) => *name,
+        Family::Serif => "Serif",
+        Family::SansSerif => "Sans serif",
+        Family::Cursive => "Cursive",
+        Family::Fantasy => "Fantasy",
+        Family::Monospace => "Monospace",
+    })
+}
+
+/// The label of a font weight, e.g. "Extra bold".
+pub fn weight_label(weight: &Weight) -> String {
+    String::from(match weight {
+        Weight::Thin => "Thin",
+        Weight::ExtraLight => "Extra light",
+        Weight::Light => "Light",
+        Weight::Normal => "Normal",
+        Weight::Medium => "Medium",
+        Weight::Semibold => "Semibold",
+        Weight::Bold => "Bold",
+        Weight::ExtraBold => "Extra bold",
+        Weight::Black => "Black",
+    })
+}
+
+/// The label of a font style, e.g. "Italic".
+pub fn style_label(style: &font::Style) -> String {
+    String::from(match style {
+        font::Style::Normal => "Normal",
+        font::Style::Italic => "Italic",
+        font::Style::Oblique => "Oblique",
+    })
+}
+
+/// The label of a horizontal alignment, e.g. "Center".
+pub fn alignment_label(alignment: &Horizontal) -> String {
+    String::from(match alignment {
+        Horizontal::Left => "Left",
+        Horizontal::Center => "Center",
+        Horizontal::Right => "Right",
+    })
+}
+
+/// Formats a `length` as `fill`, `shrink`, `portion 2`, or `100px`.
+pub fn format_length(length: Length) -> String {
+    if let Length::FillPortion(portion) = length {
+        format!("portion {portion}")
+    } else if let Length::Fixed(pixels) = length {
+        format!("{pixels}px")
+    } else if length == Length::Fill {
+        String::from("fill")
+    } else {
+        String::from("shrink")
+    }
+}
+
+/// Parses a length formatted by [`format_length`], where the `px` of a fixed length is optional.
+pub fn parse_length(input: &str) -> Option<Length> {
+    let input = input.trim().to_lowercase();
+    match input.as_str() {
+        "fill" => Some(Length::Fill),
+        "shrink" => Some(Length::Shrink),
+        _ => match input.strip_prefix("portion") {
+            Some(portion) => Some(Length::FillPortion(portion.trim().parse().ok()?)),
+            None => {
+                let pixels: f32 = input
+                    .strip_suffix("px")
+                    .unwrap_or(input.as_str())
+                    .trim()
+                    .parse()
+                    .ok()?;
+                (pixels.is_finite() && pixels >= 0.0).then_some(Length::Fixed(pixels))
+            }
+        },
+    }
+}
+
+/// Parses the sides of a padding like CSS does, i.e. one number for all sides,
+/// two for the vertical and horizontal sides, or four starting at the top and going clockwise.
+pub fn parse_padding(input: &str) -> Option<Padding> {
+    let sides = input
+        .split_whitespace()
+        .map(|side| side.parse::<f32>().ok().filter(|side| *side >= 0.0))
+        .collect::<Option<Vec<_>>>()?;
+    let [top, right, bottom, left] = match sides[..] {
+        [all] => [all; 4],
+        [vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+        [top, right, bottom, left] => [top, right, bottom, left],
+        _ => return None,
+    };
+    Some(Padding {
+        top,
+        right,
+        bottom,
+        left,
+    })
+}
+
+/// Parses a horizontal alignment from its label, ignoring the case.
+pub fn parse_alignment(input: &str) -> Option<Horizontal> {
+    ALIGNMENTS
+        .into_iter()
+        .find(|alignment| alignment_label(alignment).eq_ignore_ascii_case(input.trim()))
+}
+
+/// Formats a `font` as its family, weight, and style, e.g. `monospace bold italic`.
+///
+/// Named families are left out since they can't be parsed, along with a normal style.
+pub fn format_font(font: Font) -> String {
+    let family = (!matches!(font.family, Family::Name(_))).then(|| family_label(&font.family));
+    let style = (font.style != font::Style::Normal).then(|| style_label(&font.style));
+    family
+        .into_iter()
+        .chain([weight_label(&font.weight)])
+        .chain(style)
+        .map(|label| token(&label))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses a font formatted by [`format_font`], keeping the family of the `current` font
+/// if the input doesn't have one.
+pub fn parse_font(input: &str, current: Font) -> Option<Font> {
+    let mut font = Font {
+        weight: Weight::Normal,
+        style: font::Style::Normal,
+        ..current
+    };
+    for word in input.split_whitespace() {
+        let word = word.to_lowercase();
+        if let Some(family) = FAMILIES
+            .into_iter()
+            .find(|f| token(&family_label(f)) == word)
+        {
+            font.family = family;
+        } else if let Some(weight) = WEIGHTS
+            .into_iter()
+            .find(|w| token(&weight_label(w)) == word)
+        {
+            font.weight = weight;
+        } else if let Some(style) = STYLES.into_iter().find(|s| token(&style_label(s)) == word) {
+            font.style = style;
+        } else {
+            return None;
+        }
+    }
+    Some(font)
+}
+
+/// Parses one of the built-in themes from its name, ignoring the case.
+pub fn parse_theme(input: &str) -> Option<Theme> {
+    Theme::ALL
+        .iter()
+        .find(|theme| theme.to_string().eq_ignore_ascii_case(input.trim()))
+        .cloned()
+}
+
+/// A label as a single lowercase word, e.g. `extra-bold` for "Extra bold".
+fn token(label: &str) -> String {
+    label.to_lowercase().replace(' ', "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lengths parse back to themselves after being formatted, and paddings parse like CSS.
+    #[test]
+    fn lengths_round_trip_and_paddings_parse() {
+        for length in [
+            Length::Fill,
+            Length::Shrink,
+            Length::FillPortion(3),
+            Length::Fixed(120.5),
+        ] {
+            assert_eq!(parse_length(&format_length(length)), Some(length));
+        }
+        assert_eq!(parse_length("Portion 2"), Some(Length::FillPortion(2)));
+        assert_eq!(parse_length("64"), Some(Length::Fixed(64.0)));
+        assert_eq!(parse_length("-1px"), None);
+
+        let padding = Padding {
+            top: 1.0,
+            right: 2.0,
+            bottom: 3.0,
+            left: 4.0,
+        };
+        assert_eq!(parse_padding("1 2 3 4"), Some(padding));
+        assert_eq!(parse_padding("8"), Some(Padding::new(8.0)));
+        assert_eq!(
+            parse_padding("4 8"),
+            Some(Padding {
+                top: 4.0,
+                right: 8.0,
+                bottom: 4.0,
+                left: 8.0,
+            })
+        );
+        assert_eq!(parse_padding("1 2 3"), None);
+    }
+
+    /// Fonts keep a named family that can't be parsed.
+    #[test]
+    fn fonts_round_trip() {
+        let font = Font {
+            weight: Weight::ExtraBold,
+            style: font::Style::Italic,
+            ..Font::MONOSPACE
+        };
+        assert_eq!(format_font(font), "monospace extra-bold italic");
+        assert_eq!(parse_font(&format_font(font), Font::DEFAULT), Some(font));
+
+        let named = Font::with_name("Fira Sans");
+        assert_eq!(format_font(named), "normal");
+        assert_eq!(
+            parse_font("bold", named),
+            Some(Font {
+                weight: Weight::Bold,
+                ..named
+            })
+        );
+        assert_eq!(parse_font("heavy", named), None);
+    }
+
+    /// Alignments and themes are parsed from their labels.
+    #[test]
+    fn alignments_and_themes_parse_labels() {
+        assert_eq!(parse_alignment("center"), Some(Horizontal::Center));
+        assert_eq!(parse_alignment("middle"), None);
+        assert_eq!(parse_theme("dark"), Some(Theme::Dark));
+    }
+}
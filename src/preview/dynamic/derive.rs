@@ -0,0 +1,316 @@
+use iced::{Color, Font, Length, Padding, Theme, alignment::Horizontal};
+
+use crate::dynamic::{
+    ExtractParams,
+    param::{
+        AlignmentParam, BoolParam, ColorParam, DynamicParam, FloatParam, FontParam, LengthParam,
+        ListParam, NumberParam, OptionalParam, PaddingParam, TextParam, ThemeParam,
+    },
+};
+
+/// A struct whose fields are adjustable parameters, which is implemented by
+/// `#[derive(snowscape::Params)]`.
+///
+/// Each field becomes a parameter named after the field, e.g. `font_size` becomes "Font size",
+/// and the doc comment of the field is shown as a tooltip of its name.
+/// Fields can be configured with the `param` attribute:
+///
+/// - `#[param(name = "Size")]` changes the name of the parameter.
+/// - `#[param(slider = 0.0..=1.0)]` shows an `f32` field as a slider.
+/// - `#[param(range = 0.0..=1.0, step = 0.05, unit = "%")]` configures an `f32` or `f64` field.
+/// - `#[param(select = [Size::Small, Size::Large])]` chooses the field from a list of options.
+/// - `#[param(select)]` chooses the field from the values of its [`ParamEnum`] type.
+/// - `#[param(select, segmented)]` shows the options as segmented buttons.
+///
+/// Fields without a `select` or `slider` attribute must implement [`DefaultParam`].
+///
+/// [`ParamEnum`]: crate::dynamic::ParamEnum
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Clone, snowscape::Params)]
+/// struct Card {
+///     /// The title at the top of the card.
+///     title: String,
+///     #[param(slider = 0.0..=1.0)]
+///     opacity: f32,
+/// }
+///
+/// dynamic::stateless(
+///     "Card",
+///     dynamic::params(Card {
+///         title: String::from("Hello"),
+///         opacity: 1.0,
+///     }),
+///     |card| view(&card.title, card.opacity),
+/// )
+/// ```
+pub trait Params: Clone + Send + 'static {
+    /// The parameters of the fields, which extract the values of this struct.
+    type Fields: ExtractParams<Values = Self>;
+
+    /// Creates the parameters of the fields, using the current values as their defaults.
+    fn into_fields(self) -> Self::Fields;
+}
+
+/// Create the dynamic parameters of a struct deriving [`Params`], using its `values` as defaults.
+pub fn params<T: Params>(values: T) -> T::Fields {
+    values.into_fields()
+}
+
+/// A type with a parameter that's used for fields of a struct deriving [`Params`]
+/// when they don't choose another kind of parameter.
+pub trait DefaultParam: Sized {
+    /// The kind of parameter used for the type.
+    type Param: DynamicParam<Value = Self>;
+
+    /// Creates the parameter with the given `name` and default `value`.
+    fn param(name: &str, value: Self) -> Self::Param;
+}
+
+impl DefaultParam for String {
+    type Param = TextParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::text(name, value)
+    }
+}
+
+impl DefaultParam for i32 {
+    type Param = NumberParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::number(name, value)
+    }
+}
+
+impl DefaultParam for bool {
+    type Param = BoolParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::boolean(name, value)
+    }
+}
+
+impl DefaultParam for f32 {
+    type Param = FloatParam<f32>;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::float(name, value)
+    }
+}
+
+impl DefaultParam for f64 {
+    type Param = FloatParam<f64>;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::float(name, value)
+    }
+}
+
+impl DefaultParam for Color {
+    type Param = ColorParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::color(name, value)
+    }
+}
+
+impl DefaultParam for Length {
+    type Param = LengthParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::length(name, value)
+    }
+}
+
+impl DefaultParam for Padding {
+    type Param = PaddingParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::padding(name, value)
+    }
+}
+
+impl DefaultParam for Horizontal {
+    type Param = AlignmentParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::alignment(name, value)
+    }
+}
+
+impl DefaultParam for Font {
+    type Param = FontParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::font(name, value)
+    }
+}
+
+impl DefaultParam for Theme {
+    type Param = ThemeParam;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::theme(name, value)
+    }
+}
+
+/// Optional fields start with the default value of their type while they're disabled.
+impl<T: DefaultParam + Default> DefaultParam for Option<T> {
+    type Param = OptionalParam<T::Param>;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        match value {
+            Some(value) => crate::dynamic::optional(T::param(name, value)),
+            None => crate::dynamic::optional(T::param(name, T::default())).disabled(),
+        }
+    }
+}
+
+/// New items of list fields start with the default value of their type.
+impl<T: DefaultParam + Default> DefaultParam for Vec<T> {
+    type Param = ListParam<T::Param>;
+
+    fn param(name: &str, value: Self) -> Self::Param {
+        crate::dynamic::list(name, T::param(name, T::default()), value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::{Param, ParamEnum, Value};
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, crate::ParamEnum)]
+    enum Size {
+        Small,
+        /// Fills the available space.
+        ExtraLarge,
+        #[param(name = "Custom size")]
+        Custom,
+    }
+
+    #[derive(Debug, Clone, PartialEq, crate::Params)]
+    struct Card {
+        /// The title at the top of the card.
+        title: String,
+        #[param(slider = 0.0..=1.0)]
+        opacity: f32,
+        #[param(name = "Card size", select, segmented)]
+        size: Size,
+        #[param(select = [4, 8, 16])]
+        padding: i32,
+        #[param(range = 0.0..=10.0, step = 0.5, unit = "px")]
+        border_width: f64,
+    }
+
+    fn card() -> Card {
+        Card {
+            title: String::from("Hello"),
+            opacity: 1.0,
+            size: Size::Small,
+            padding: 8,
+            border_width: 1.0,
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, crate::Params)]
+    struct Post {
+        subtitle: Option<String>,
+        tags: Vec<i32>,
+    }
+
+    /// Fields become parameters named after them, with their doc comments as descriptions.
+    #[test]
+    fn fields_become_params() {
+        let params = params(card()).to_params();
+        let names: Vec<&str> = params.iter().map(|param| param.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["Title", "Opacity", "Card size", "Padding", "Border width"]
+        );
+        assert_eq!(
+            params[0],
+            Param::new("Title", Value::Text(String::from("Hello")))
+                .description("The title at the top of the card.")
+        );
+        assert_eq!(params[1].value, Value::Slider(1.0, 0.0..=1.0));
+        assert_eq!(params[1].description, None);
+        assert_eq!(
+            params[2].value,
+            Value::Select(
+                0,
+                vec![
+                    String::from("Small"),
+                    String::from("Extra large"),
+                    String::from("Custom size")
+                ]
+            )
+        );
+        assert!(params[2].segmented);
+    }
+
+    /// Enums list their variants with the doc comments as descriptions.
+    #[test]
+    fn enums_list_variants() {
+        assert_eq!(
+            Size::variants(),
+            [Size::Small, Size::ExtraLarge, Size::Custom]
+        );
+        assert_eq!(Size::ExtraLarge.label(), "Extra large");
+        assert_eq!(
+            Size::ExtraLarge.description().as_deref(),
+            Some("Fills the available space.")
+        );
+        assert_eq!(Size::Small.description(), None);
+    }
+
+    /// Updating a parameter changes the matching field of the extracted struct.
+    #[test]
+    fn updates_extract_struct() {
+        let mut params = params(card());
+        assert_eq!(params.extract(), card());
+
+        params.update_index(2, Value::Select(1, Vec::new()));
+        params.update_index(3, Value::Select(2, Vec::new()));
+        params.update_index(5, Value::Bool(true));
+        assert_eq!(
+            params.extract(),
+            Card {
+                size: Size::ExtraLarge,
+                padding: 16,
+                ..card()
+            }
+        );
+    }
+
+    /// Optional and list fields start new values with the default value of their type.
+    #[test]
+    fn optional_and_list_fields() {
+        let mut params = params(Post {
+            subtitle: None,
+            tags: vec![1, 2],
+        });
+        let tag = Param::new("Tags", Value::I32(0));
+        assert_eq!(
+            params.to_params()[1].value,
+            Value::List(
+                vec![tag.with_value(Value::I32(1)), tag.with_value(Value::I32(2))],
+                Box::new(tag)
+            )
+        );
+
+        let subtitle = Param::new("Subtitle", Value::Text(String::from("Hi")));
+        params.update_index(0, Value::Optional(true, Box::new(subtitle)));
+        assert_eq!(
+            params.extract(),
+            Post {
+                subtitle: Some(String::from("Hi")),
+                tags: vec![1, 2],
+            }
+        );
+    }
+}
@@ -7,7 +7,8 @@ use crate::dynamic::{
 /// which allows dynamic previews to manage adjustable parameters.
 ///
 /// You can extract values from single parameters or tuples of parameters.
-/// Tuples are supported up to arity 8.
+/// Tuples are supported up to arity 8, while structs deriving [`Params`](crate::dynamic::Params)
+/// can have any number of fields.
 pub trait ExtractParams: Clone + Send + 'static {
     /// The type of values extracted from these parameters.
     type Values: Clone + Send + 'static;
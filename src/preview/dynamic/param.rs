@@ -1,8 +1,8 @@
-use std::{fmt::Display, ops::RangeInclusive};
+use std::{fmt::Display, marker::PhantomData, ops::RangeInclusive};
 
-use iced::Color;
+use iced::{Color, Font, Length, Padding, Theme, alignment::Horizontal};
 
-use crate::dynamic::Value;
+use crate::dynamic::{Float, Value, length_value, padding_from_sides, padding_sides};
 
 /// A dynamic parameter that can be adjusted in the configuration pane.
 #[derive(Debug, Clone, PartialEq)]
@@ -11,6 +11,12 @@ pub struct Param {
     pub name: String,
     /// The current value of the parameter.
     pub value: Value,
+    /// A description of the parameter that's shown as a tooltip, if any.
+    pub description: Option<String>,
+    /// Descriptions of the options of a select parameter, in the same order as its options.
+    pub option_descriptions: Vec<Option<String>>,
+    /// Whether a select parameter shows its options as segmented buttons instead of a pick list.
+    pub segmented: bool,
 }
 
 impl Param {
@@ -19,6 +25,23 @@ impl Param {
         Param {
             name: name.into(),
             value: value.into(),
+            description: None,
+            option_descriptions: Vec::new(),
+            segmented: false,
+        }
+    }
+
+    /// Add a description to the parameter, which is shown as a tooltip of its name.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A copy of the parameter with another `value`, e.g. for an item of a list parameter.
+    pub fn with_value(&self, value: Value) -> Self {
+        Param {
+            value,
+            ..self.clone()
         }
     }
 }
@@ -59,6 +82,14 @@ pub trait DynamicParam: Clone + Send + 'static {
 
     /// Gets the typed value.
     fn value(&self) -> Self::Value;
+
+    /// Sets the typed value, e.g. to create the items of a list parameter from their defaults.
+    ///
+    /// Does nothing by default, so a custom parameter that doesn't implement this keeps
+    /// its own value when it's used as the item of a [`list`].
+    fn set_value(&mut self, value: Self::Value) {
+        let _ = value;
+    }
 }
 
 /// A text parameter that produces String values.
@@ -88,6 +119,10 @@ impl DynamicParam for TextParam {
     fn value(&self) -> Self::Value {
         self.value.clone()
     }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.value = value;
+    }
 }
 
 /// A number parameter that produces i32 values.
@@ -117,6 +152,99 @@ impl DynamicParam for NumberParam {
     fn value(&self) -> Self::Value {
         self.value
     }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.value = value;
+    }
+}
+
+/// A floating-point type that a [`FloatParam`] can produce, i.e. `f32` or `f64`.
+pub trait FloatType: Copy + std::fmt::Debug + Send + 'static {
+    /// Converts the value to an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` to a value of this type.
+    fn from_f64(value: f64) -> Self;
+}
+
+impl FloatType for f32 {
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl FloatType for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// A floating-point parameter with an optional range, step, and unit.
+#[derive(Debug, Clone)]
+pub struct FloatParam<T> {
+    name: String,
+    float: Float,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T: FloatType> FloatParam<T> {
+    /// Limits the value to the `range`, which entered values are validated against.
+    ///
+    /// A reversed range is flipped, and a range with a NaN bound is ignored.
+    pub fn range(mut self, range: RangeInclusive<T>) -> Self {
+        self.float.range = ordered(range.start().to_f64()..=range.end().to_f64());
+        self.float = self.float.with_value(self.float.value);
+        self
+    }
+
+    /// Sets the amount that the increment and decrement buttons change the value by,
+    /// which also sets the number of decimals shown. Defaults to `0.1`.
+    pub fn step(mut self, step: T) -> Self {
+        self.float.step = step.to_f64().abs();
+        self.float = self.float.with_value(self.float.value);
+        self
+    }
+
+    /// Sets the unit shown after the value, e.g. `px` or `%`.
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.float.unit = Some(unit.into());
+        self
+    }
+}
+
+impl<T: FloatType> DynamicParam for FloatParam<T> {
+    type Value = T;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_param(&self) -> Param {
+        Param::new(&self.name, Value::Float(self.float.clone()))
+    }
+
+    fn update(&mut self, value: Value) {
+        // The range, step, and unit always come from the parameter itself.
+        if let Value::Float(float) = value {
+            self.float = self.float.with_input(float.input);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        T::from_f64(self.float.value)
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.float = self.float.with_value(value.to_f64());
+    }
 }
 
 /// A boolean parameter that produces bool values.
@@ -146,6 +274,10 @@ impl DynamicParam for BoolParam {
     fn value(&self) -> Self::Value {
         self.value
     }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.value = value;
+    }
 }
 
 /// Create a dynamic text parameter.
@@ -178,6 +310,26 @@ pub fn number(name: impl Into<String>, value: i32) -> NumberParam {
     }
 }
 
+/// Create a dynamic floating-point parameter, which produces `f32` or `f64` values
+/// depending on the type of the `value`.
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let opacity = dynamic::float("Opacity", 0.5)
+///     .range(0.0..=1.0)
+///     .step(0.05)
+///     .unit("%");
+/// ```
+pub fn float<T: FloatType>(name: impl Into<String>, value: T) -> FloatParam<T> {
+    FloatParam {
+        name: name.into(),
+        float: Float::new(value.to_f64()),
+        _type: PhantomData,
+    }
+}
+
 /// Create a dynamic boolean parameter.
 ///
 /// # Example
@@ -195,18 +347,33 @@ pub fn boolean(name: impl Into<String>, value: bool) -> BoolParam {
 
 /// A select parameter that allows choosing from a list of typed options.
 ///
-/// The type `T` must implement `Display` for rendering in the UI,
-/// and `Clone + PartialEq + Send + 'static` for type-safe value handling.
+/// The type `T` must implement `Clone + PartialEq + Send + 'static` for type-safe value handling,
+/// while the labels of the options are created along with the parameter.
 #[derive(Debug, Clone)]
 pub struct SelectParam<T> {
     name: String,
     options: Vec<T>,
+    /// The labels shown for the `options`.
+    labels: Vec<String>,
+    /// The descriptions of the `options`, if they have any.
+    descriptions: Vec<Option<String>>,
     selected_index: usize,
+    /// Whether the options are shown as segmented buttons.
+    segmented: bool,
+}
+
+impl<T> SelectParam<T> {
+    /// Shows the options as segmented buttons instead of a pick list,
+    /// which suits a small number of options with short labels.
+    pub fn segmented(mut self) -> Self {
+        self.segmented = true;
+        self
+    }
 }
 
 impl<T> DynamicParam for SelectParam<T>
 where
-    T: std::fmt::Display + Clone + PartialEq + Send + 'static,
+    T: Clone + PartialEq + Send + 'static,
 {
     type Value = T;
 
@@ -215,11 +382,14 @@ where
     }
 
     fn to_param(&self) -> Param {
-        let option_strings: Vec<String> = self.options.iter().map(|o| o.to_string()).collect();
-        Param::new(
-            &self.name,
-            Value::Select(self.selected_index, option_strings),
-        )
+        Param {
+            option_descriptions: self.descriptions.clone(),
+            segmented: self.segmented,
+            ..Param::new(
+                &self.name,
+                Value::Select(self.selected_index, self.labels.clone()),
+            )
+        }
     }
 
     fn update(&mut self, value: Value) {
@@ -233,6 +403,12 @@ where
     fn value(&self) -> Self::Value {
         self.options[self.selected_index].clone()
     }
+
+    fn set_value(&mut self, value: Self::Value) {
+        if let Some(index) = self.options.iter().position(|option| *option == value) {
+            self.selected_index = index;
+        }
+    }
 }
 
 /// Create a dynamic select parameter that allows choosing from a list of options.
@@ -275,7 +451,64 @@ where
     SelectParam {
         name: name.into(),
         options: options.to_vec(),
+        labels: options.iter().map(ToString::to_string).collect(),
+        descriptions: vec![None; options.len()],
         selected_index,
+        segmented: false,
+    }
+}
+
+/// A type with a fixed set of values that a select parameter can choose from,
+/// which is implemented for enums without fields by `#[derive(snowscape::ParamEnum)]`.
+///
+/// The derived labels are the names of the variants in sentence case, e.g. "Extra large"
+/// for `ExtraLarge`, or the name given by `#[param(name = "XL")]`.
+/// The doc comments of the variants become the descriptions of the options.
+///
+/// Fields of a struct deriving [`Params`](crate::dynamic::Params) that are marked with
+/// `#[param(select)]` list these values as their options.
+pub trait ParamEnum: Clone + PartialEq + Send + 'static {
+    /// All values in the order they're listed.
+    fn variants() -> Vec<Self>;
+
+    /// The label shown for the value.
+    fn label(&self) -> String;
+
+    /// The description of the value, which is shown along with its label.
+    fn description(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Create a dynamic select parameter that allows choosing from all values of a [`ParamEnum`].
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, snowscape::ParamEnum)]
+/// enum Alignment {
+///     /// Aligns the content to the start of the line.
+///     Left,
+///     Center,
+///     Right,
+/// }
+///
+/// let alignment = dynamic::enumeration("Align", Alignment::Center).segmented();
+/// ```
+pub fn enumeration<T: ParamEnum>(name: impl Into<String>, default: T) -> SelectParam<T> {
+    let options = T::variants();
+    let selected_index = options
+        .iter()
+        .position(|o| *o == default)
+        .expect("default value must be one of the variants");
+
+    SelectParam {
+        name: name.into(),
+        labels: options.iter().map(ParamEnum::label).collect(),
+        descriptions: options.iter().map(ParamEnum::description).collect(),
+        options,
+        selected_index,
+        segmented: false,
     }
 }
 
@@ -307,10 +540,16 @@ impl DynamicParam for SliderParam {
     fn value(&self) -> Self::Value {
         self.value
     }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.value = value.clamp(*self.range.start(), *self.range.end());
+    }
 }
 
 /// Create a dynamic slider parameter with a range.
 ///
+/// A reversed range is flipped, and a range with a NaN bound becomes `0.0..=1.0`.
+///
 /// # Example
 ///
 /// ```
@@ -318,6 +557,7 @@ impl DynamicParam for SliderParam {
 /// let padding = dynamic::slider("Padding", 0.0..=100.0, 16.0);
 /// ```
 pub fn slider(name: impl Into<String>, range: RangeInclusive<f32>, default: f32) -> SliderParam {
+    let range = ordered(range).unwrap_or(0.0..=1.0);
     SliderParam {
         name: name.into(),
         value: default.clamp(*range.start(), *range.end()),
@@ -325,6 +565,16 @@ pub fn slider(name: impl Into<String>, range: RangeInclusive<f32>, default: f32)
     }
 }
 
+/// The `range` with its bounds in order, so values can be clamped to it,
+/// or `None` if either bound is NaN.
+fn ordered<T: PartialOrd>(range: RangeInclusive<T>) -> Option<RangeInclusive<T>> {
+    let (start, end) = range.into_inner();
+    match start.partial_cmp(&end)? {
+        std::cmp::Ordering::Greater => Some(end..=start),
+        _ => Some(start..=end),
+    }
+}
+
 /// A color parameter that produces `Color` values.
 #[derive(Debug, Clone)]
 pub struct ColorParam {
@@ -352,6 +602,10 @@ impl DynamicParam for ColorParam {
     fn value(&self) -> Self::Value {
         self.color
     }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.color = value;
+    }
 }
 
 /// Create a dynamic color parameter.
@@ -369,6 +623,423 @@ pub fn color(name: impl Into<String>, default: Color) -> ColorParam {
     }
 }
 
+/// A length parameter that produces `Length` values, e.g. for the width of a widget.
+#[derive(Debug, Clone)]
+pub struct LengthParam {
+    name: String,
+    length: Length,
+    /// The pixels of a `Fixed` length, kept while another kind is chosen.
+    pixels: Float,
+}
+
+impl DynamicParam for LengthParam {
+    type Value = Length;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_param(&self) -> Param {
+        Param::new(&self.name, Value::Length(self.length, self.pixels.clone()))
+    }
+
+    fn update(&mut self, value: Value) {
+        // The range, step, and unit of the pixels always come from the parameter itself.
+        if let Value::Length(length, pixels) = value {
+            self.length = length;
+            self.pixels = self.pixels.with_input(pixels.input);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.length
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        if let Value::Length(length, pixels) = length_value(value, &self.pixels) {
+            self.length = length;
+            self.pixels = pixels;
+        }
+    }
+}
+
+/// Create a dynamic length parameter, which can be `Fill`, `Shrink`, `FillPortion`, or `Fixed`.
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let width = dynamic::length("Width", iced::Length::Fill);
+/// ```
+pub fn length(name: impl Into<String>, default: impl Into<Length>) -> LengthParam {
+    let length = default.into();
+    // Switching to a fixed length starts from a sensible default.
+    let pixels = match length {
+        Length::Fixed(pixels) => pixels,
+        _ => 100.0,
+    };
+    LengthParam {
+        name: name.into(),
+        length,
+        pixels: Float::pixels(pixels),
+    }
+}
+
+/// A padding parameter that produces `Padding` values with a size for each side.
+#[derive(Debug, Clone)]
+pub struct PaddingParam {
+    name: String,
+    /// The pixels entered for each side, going clockwise from the top.
+    sides: Box<[Float; 4]>,
+}
+
+impl DynamicParam for PaddingParam {
+    type Value = Padding;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_param(&self) -> Param {
+        Param::new(&self.name, Value::Padding(self.sides.clone()))
+    }
+
+    fn update(&mut self, value: Value) {
+        // The range, step, and unit of each side always come from the parameter itself.
+        if let Value::Padding(sides) = value {
+            let updated =
+                std::array::from_fn(|side| self.sides[side].with_input(sides[side].input.clone()));
+            *self.sides = updated;
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        padding_from_sides(&self.sides)
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.sides = padding_sides(value);
+    }
+}
+
+/// Create a dynamic padding parameter with a size for each side.
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let padding = dynamic::padding("Padding", [8, 16]);
+/// ```
+pub fn padding(name: impl Into<String>, default: impl Into<Padding>) -> PaddingParam {
+    PaddingParam {
+        name: name.into(),
+        sides: padding_sides(default.into()),
+    }
+}
+
+/// An alignment parameter that produces horizontal alignments.
+#[derive(Debug, Clone)]
+pub struct AlignmentParam {
+    name: String,
+    alignment: Horizontal,
+}
+
+impl DynamicParam for AlignmentParam {
+    type Value = Horizontal;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_param(&self) -> Param {
+        Param::new(&self.name, Value::Alignment(self.alignment))
+    }
+
+    fn update(&mut self, value: Value) {
+        if let Value::Alignment(alignment) = value {
+            self.alignment = alignment;
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.alignment
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.alignment = value;
+    }
+}
+
+/// Create a dynamic horizontal alignment parameter.
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let align_x = dynamic::alignment("Align", iced::alignment::Horizontal::Center);
+/// ```
+pub fn alignment(name: impl Into<String>, default: Horizontal) -> AlignmentParam {
+    AlignmentParam {
+        name: name.into(),
+        alignment: default,
+    }
+}
+
+/// A font parameter that produces `Font` values with an adjustable family, weight, and style.
+#[derive(Debug, Clone)]
+pub struct FontParam {
+    name: String,
+    font: Font,
+}
+
+impl DynamicParam for FontParam {
+    type Value = Font;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_param(&self) -> Param {
+        Param::new(&self.name, Value::Font(self.font))
+    }
+
+    fn update(&mut self, value: Value) {
+        if let Value::Font(font) = value {
+            self.font = font;
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.font
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.font = value;
+    }
+}
+
+/// Create a dynamic font parameter, whose family, weight, and style can be changed.
+///
+/// The named family of a custom font stays one of the options.
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let font = dynamic::font("Font", iced::Font::MONOSPACE);
+/// ```
+pub fn font(name: impl Into<String>, default: Font) -> FontParam {
+    FontParam {
+        name: name.into(),
+        font: default,
+    }
+}
+
+/// A theme parameter that produces one of the built-in themes.
+#[derive(Debug, Clone)]
+pub struct ThemeParam {
+    name: String,
+    theme: Theme,
+}
+
+impl DynamicParam for ThemeParam {
+    type Value = Theme;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_param(&self) -> Param {
+        Param::new(&self.name, Value::Theme(self.theme.clone()))
+    }
+
+    fn update(&mut self, value: Value) {
+        if let Value::Theme(theme) = value {
+            self.theme = theme;
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.theme.clone()
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.theme = value;
+    }
+}
+
+/// Create a dynamic theme parameter that chooses from the built-in themes, e.g. to
+/// preview a component in a theme other than the one the app uses.
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let theme = dynamic::theme("Theme", iced::Theme::Dark);
+/// ```
+pub fn theme(name: impl Into<String>, default: Theme) -> ThemeParam {
+    ThemeParam {
+        name: name.into(),
+        theme: default,
+    }
+}
+
+/// An optional parameter that produces the value of its inner parameter while it's enabled.
+#[derive(Debug, Clone)]
+pub struct OptionalParam<P> {
+    param: P,
+    enabled: bool,
+}
+
+impl<P> OptionalParam<P> {
+    /// Starts without a value, i.e. with the parameter disabled.
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+}
+
+impl<P: DynamicParam> DynamicParam for OptionalParam<P> {
+    type Value = Option<P::Value>;
+
+    fn name(&self) -> &str {
+        self.param.name()
+    }
+
+    fn to_param(&self) -> Param {
+        let param = self.param.to_param();
+        Param {
+            description: param.description.clone(),
+            ..Param::new(
+                param.name.clone(),
+                Value::Optional(self.enabled, Box::new(param)),
+            )
+        }
+    }
+
+    fn update(&mut self, value: Value) {
+        if let Value::Optional(enabled, param) = value {
+            self.enabled = enabled;
+            self.param.update(param.value);
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.enabled.then(|| self.param.value())
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.enabled = value.is_some();
+        if let Some(value) = value {
+            self.param.set_value(value);
+        }
+    }
+}
+
+/// Create a dynamic optional parameter from another `param`, which is shown with a
+/// checkbox that enables it and produces `None` while it's disabled.
+///
+/// The parameter starts enabled unless it's [`disabled`](OptionalParam::disabled).
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let subtitle = dynamic::optional(dynamic::text("Subtitle", "Posted yesterday")).disabled();
+/// ```
+pub fn optional<P: DynamicParam>(param: P) -> OptionalParam<P> {
+    OptionalParam {
+        param,
+        enabled: true,
+    }
+}
+
+/// A list parameter that produces the values of a number of items,
+/// which can be added, removed, and reordered.
+#[derive(Debug, Clone)]
+pub struct ListParam<P> {
+    name: String,
+    /// The parameter that new items are copies of.
+    item: P,
+    items: Vec<P>,
+}
+
+impl<P: DynamicParam> DynamicParam for ListParam<P> {
+    type Value = Vec<P::Value>;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_param(&self) -> Param {
+        Param::new(
+            &self.name,
+            Value::List(
+                self.items.iter().map(DynamicParam::to_param).collect(),
+                Box::new(self.item.to_param()),
+            ),
+        )
+    }
+
+    fn update(&mut self, value: Value) {
+        // Each item carries its whole value, so the items are recreated from the template.
+        if let Value::List(items, _) = value {
+            self.items = items
+                .into_iter()
+                .map(|item| {
+                    let mut param = self.item.clone();
+                    param.update(item.value);
+                    param
+                })
+                .collect();
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.items.iter().map(DynamicParam::value).collect()
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.items = value
+            .into_iter()
+            .map(|value| {
+                let mut param = self.item.clone();
+                param.set_value(value);
+                param
+            })
+            .collect();
+    }
+}
+
+/// Create a dynamic list parameter whose items are adjusted by copies of the `item` parameter,
+/// starting with an item for each of the `defaults`.
+///
+/// New items start with the value of the `item` parameter.
+///
+/// # Example
+///
+/// ```
+/// use snowscape::dynamic;
+/// let fruits = dynamic::list(
+///     "Fruits",
+///     dynamic::text("Fruit", ""),
+///     [String::from("Apple"), String::from("Banana")],
+/// );
+/// ```
+pub fn list<P: DynamicParam>(
+    name: impl Into<String>,
+    item: P,
+    defaults: impl IntoIterator<Item = P::Value>,
+) -> ListParam<P> {
+    let mut list = ListParam {
+        name: name.into(),
+        item,
+        items: Vec::new(),
+    };
+    list.set_value(defaults.into_iter().collect());
+    list
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,6 +1072,38 @@ mod tests {
         assert_eq!(param.value(), 100);
     }
 
+    #[test]
+    fn float_param_basic() {
+        let param = float("Opacity", 0.5f32).range(0.0..=1.0).step(0.05);
+        assert_eq!(param.name(), "Opacity");
+        assert_eq!(param.value(), 0.5);
+        assert_eq!(param.to_param().value, Value::Float(param.float.clone()));
+    }
+
+    /// Entered text is kept even while invalid, but only valid values change the value.
+    #[test]
+    fn float_param_update() {
+        let mut param = float("Opacity", 0.5).range(0.0..=1.0);
+        param.update(Value::Float(param.float.with_input(String::from("0.25"))));
+        assert_eq!(param.value(), 0.25);
+
+        param.update(Value::Float(param.float.with_input(String::from("2"))));
+        assert_eq!(param.value(), 0.25);
+        assert_eq!(param.float.input, "2");
+    }
+
+    /// Reversed ranges are flipped and ranges with a NaN bound are ignored instead of panicking.
+    #[test]
+    fn float_param_unordered_range() {
+        let reversed = float("Opacity", 2.0).range(1.0..=0.0);
+        assert_eq!(reversed.float.range, Some(0.0..=1.0));
+        assert_eq!(reversed.value(), 1.0);
+
+        let nan = float("Opacity", 2.0).range(0.0..=f64::NAN);
+        assert_eq!(nan.float.range, None);
+        assert_eq!(nan.value(), 2.0);
+    }
+
     #[test]
     fn bool_param_basic() {
         let param = boolean("my_bool", true);
@@ -471,6 +1174,19 @@ mod tests {
         assert_eq!(param.value(), 100.0);
     }
 
+    /// Sliders with a reversed range or a NaN bound still clamp their values.
+    #[test]
+    fn slider_param_unordered_range() {
+        let mut reversed = slider("Padding", 100.0..=0.0, 150.0);
+        assert_eq!(reversed.value(), 100.0);
+        reversed.set_value(-5.0);
+        assert_eq!(reversed.value(), 0.0);
+
+        let mut nan = slider("Padding", f32::NAN..=100.0, 50.0);
+        nan.set_value(5.0);
+        assert_eq!(nan.value(), 1.0);
+    }
+
     #[test]
     fn color_param_basic() {
         let red = Color::from_rgb(1.0, 0.0, 0.0);
@@ -489,4 +1205,75 @@ mod tests {
         let value = param.value();
         assert_eq!(value, blue);
     }
+
+    /// Optional parameters produce `None` while disabled and keep the inner value.
+    #[test]
+    fn optional_param_update() {
+        let mut param = optional(number("Count", 3));
+        assert_eq!(param.value(), Some(3));
+
+        let Value::Optional(_, inner) = param.to_param().value else {
+            panic!("optional parameters have an optional value");
+        };
+        param.update(Value::Optional(
+            false,
+            Box::new(inner.with_value(Value::I32(5))),
+        ));
+        assert_eq!(param.value(), None);
+
+        param.set_value(Some(7));
+        assert_eq!(param.value(), Some(7));
+        assert_eq!(optional(number("Count", 3)).disabled().value(), None);
+    }
+
+    /// List items are recreated from the item parameter when they change.
+    #[test]
+    fn list_param_update() {
+        let mut param = list("Sizes", slider("Size", 0.0..=10.0, 1.0), [2.0, 20.0]);
+        assert_eq!(param.value(), [2.0, 10.0]);
+
+        let Value::List(items, item) = param.to_param().value else {
+            panic!("list parameters have a list value");
+        };
+        assert_eq!(item.value, Value::Slider(1.0, 0.0..=10.0));
+
+        let reordered = vec![items[1].clone(), items[0].clone(), (*item).clone()];
+        param.update(Value::List(reordered, item));
+        assert_eq!(param.value(), [10.0, 2.0, 1.0]);
+    }
+
+    /// Custom parameters don't need to set their typed value, in which case the items
+    /// of a list start with the value of the item parameter.
+    #[test]
+    fn custom_param_without_set_value() {
+        #[derive(Clone)]
+        struct Flag(bool);
+
+        impl DynamicParam for Flag {
+            type Value = bool;
+
+            fn name(&self) -> &str {
+                "Flag"
+            }
+
+            fn to_param(&self) -> Param {
+                Param::new("Flag", self.0)
+            }
+
+            fn update(&mut self, value: Value) {
+                if let Value::Bool(value) = value {
+                    self.0 = value;
+                }
+            }
+
+            fn value(&self) -> Self::Value {
+                self.0
+            }
+        }
+
+        assert_eq!(
+            list("Flags", Flag(true), [false, false]).value(),
+            [true, true]
+        );
+    }
 }
@@ -1,16 +1,21 @@
-use iced::{Element, Task};
+use std::sync::Arc;
+
+use iced::{Element, Subscription, Task};
 
 use crate::{
     dynamic::{ExtractParams, Param},
     message::AnyMessage,
     metadata::Metadata,
-    preview::{History, Performance, Preview, Timeline},
+    preview::{
+        BootFn, Decorator, History, Performance, Preview, Replay, ReplayError, SubscriptionFn,
+        Timeline, Variant, variant,
+    },
 };
 
 /// A dynamic stateful preview with full update/view cycle and adjustable parameters.
 pub struct Stateful<Boot, Params, State, Message, IntoTask>
 where
-    Boot: Fn() -> State,
+    Boot: BootFn<State, Message>,
     Params: ExtractParams,
     State: Send,
     Message: AnyMessage,
@@ -30,6 +35,8 @@ where
     boot: Boot,
     /// The current state of the preview.
     state: State,
+    /// The task returned by `boot` that runs once the preview is first selected.
+    boot_task: Option<Task<Message>>,
     /// The history of messages emitted by the preview.
     history: History<Message>,
     /// Performance metrics for tracking view/update function execution times.
@@ -38,11 +45,17 @@ where
     update_fn: fn(&mut State, Message) -> IntoTask,
     /// The view function that renders the preview.
     view_fn: for<'a> fn(&'a State, &'a Params::Values) -> Element<'a, Message>,
+    /// The optional function returning the subscriptions of the current state.
+    subscription_fn: Option<SubscriptionFn<State, Message>>,
+    /// Named variants of this preview that share its metadata and subscription.
+    variants: Vec<Variant<Option<SubscriptionFn<State, Message>>>>,
+    /// Decorators that wrap this preview's element.
+    decorators: Vec<Decorator>,
 }
 
 impl<Boot, Params, State, Message, IntoTask> Stateful<Boot, Params, State, Message, IntoTask>
 where
-    Boot: Fn() -> State + Send,
+    Boot: BootFn<State, Message>,
     Params: ExtractParams,
     State: Send,
     Message: AnyMessage,
@@ -57,7 +70,7 @@ where
     ) -> Self {
         let cached_params = params.to_params();
         let cached_values = params.extract();
-        let state = boot();
+        let (state, boot_task) = boot.boot();
         Self {
             metadata,
             params: params.clone(),
@@ -66,30 +79,53 @@ where
             cached_values,
             boot,
             state,
+            boot_task: Some(boot_task),
             history: History::new(),
             performance: Performance::default(),
             update_fn,
             view_fn,
+            subscription_fn: None,
+            variants: Vec::new(),
+            decorators: Vec::new(),
         }
     }
 
-    /// Add a description to the preview.
-    pub fn description(mut self, description: impl Into<String>) -> Self {
-        self.metadata = self.metadata.description(description);
+    metadata_builder_methods!();
+
+    /// Add a subscription function, e.g. to listen to timers or keyboard events.
+    /// The subscription is only active while the preview is selected or being recorded.
+    pub fn subscription(mut self, subscription_fn: fn(&State) -> Subscription<Message>) -> Self {
+        self.subscription_fn = Some(subscription_fn);
         self
     }
 
-    /// Add a group to the preview.
-    pub fn group(mut self, group: impl Into<String>) -> Self {
-        self.metadata = self.metadata.group(group);
+    /// Add a [decorator](Preview::take_decorators) that wraps this preview's element.
+    pub fn decorator<D>(mut self, decorator: D) -> Self
+    where
+        D: for<'a> Fn(Element<'a, crate::Message>) -> Element<'a, crate::Message>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.decorators.push(Arc::new(decorator));
         self
     }
 
-    /// Add tags to the preview.
-    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
-        self.metadata = self
-            .metadata
-            .tags(tags.into_iter().map(Into::into).collect());
+    /// Add a named [variant](Preview::take_variants) with different default `params`.
+    pub fn variant(mut self, name: impl Into<String>, params: Params) -> Self
+    where
+        Boot: Clone + 'static,
+        State: 'static,
+        IntoTask: 'static,
+    {
+        let boot = self.boot.clone();
+        let (update_fn, view_fn) = (self.update_fn, self.view_fn);
+        self.variants
+            .push(Variant::new(name, move |metadata, subscription_fn| {
+                let mut preview = Stateful::new(params, boot, update_fn, view_fn, metadata);
+                preview.subscription_fn = subscription_fn;
+                Box::new(preview)
+            }));
         self
     }
 }
@@ -97,7 +133,7 @@ where
 impl<Boot, Params, State, Message, IntoTask> Preview
     for Stateful<Boot, Params, State, Message, IntoTask>
 where
-    Boot: Fn() -> State + Send,
+    Boot: BootFn<State, Message>,
     Params: ExtractParams,
     State: Send,
     Message: AnyMessage,
@@ -107,6 +143,10 @@ where
         &self.metadata
     }
 
+    fn metadata_mut(&mut self) -> &mut crate::Metadata {
+        &mut self.metadata
+    }
+
     fn update(&mut self, message: crate::Message) -> Task<crate::Message> {
         match message {
             crate::Message::Component(boxed) => {
@@ -132,14 +172,16 @@ where
             }
             crate::Message::ResetPreview => {
                 // Reset state with current parameter values
-                self.state = (self.boot)();
+                let (state, task) = self.boot.boot();
+                self.state = state;
+                self.boot_task = None;
                 self.history.reset();
                 self.performance.reset();
-                Task::none()
+                task.map(|message| crate::Message::Component(Box::new(message)))
             }
             crate::Message::TimeTravel(index) => {
                 self.history.change_position(index as usize);
-                self.state = (self.boot)();
+                self.state = self.boot.boot().0;
                 self.history
                     .messages
                     .iter()
@@ -184,6 +226,19 @@ where
         })
     }
 
+    fn subscription(&self) -> Subscription<crate::Message> {
+        match self.subscription_fn {
+            Some(subscription_fn) => subscription_fn(&self.state).map(crate::Message::component),
+            None => Subscription::none(),
+        }
+    }
+
+    fn take_boot_task(&mut self) -> Task<crate::Message> {
+        self.boot_task.take().map_or_else(Task::none, |task| {
+            task.map(|message| crate::Message::Component(Box::new(message)))
+        })
+    }
+
     fn message_count(&self) -> usize {
         self.history.len()
     }
@@ -196,6 +251,26 @@ where
         Some(self.history.timeline())
     }
 
+    fn to_replay(&self) -> Option<Replay> {
+        Some(self.history.to_replay())
+    }
+
+    fn replay(&mut self, replay: Replay) -> Result<(), ReplayError> {
+        let (messages, result) = History::downcast(&replay);
+        // The boot task's messages are part of the history that's being replayed.
+        if !messages.is_empty() {
+            self.boot_task = None;
+        }
+        for message in messages {
+            self.history.push(message.clone());
+            _ = (self.update_fn)(&mut self.state, message);
+        }
+        if result.is_ok() && replay.position < self.history.len() {
+            _ = self.update(crate::Message::TimeTravel(replay.position as u32));
+        }
+        result
+    }
+
     fn params(&self) -> &[Param] {
         &self.cached_params
     }
@@ -203,10 +278,23 @@ where
     fn performance(&self) -> Option<&Performance> {
         Some(&self.performance)
     }
+
+    fn take_variants(&mut self) -> Vec<Box<dyn Preview>> {
+        variant::build_all(
+            std::mem::take(&mut self.variants),
+            &self.metadata,
+            self.subscription_fn,
+        )
+    }
+
+    fn take_decorators(&mut self) -> Vec<Decorator> {
+        std::mem::take(&mut self.decorators)
+    }
 }
 
 /// Create a new dynamic stateful preview, allowing users to adjust parameters
 /// that affect the view at runtime.
+#[track_caller]
 pub fn stateful<Boot, Params, State, Message, IntoTask>(
     label: impl Into<String>,
     params: Params,
@@ -216,11 +304,11 @@ pub fn stateful<Boot, Params, State, Message, IntoTask>(
 ) -> Stateful<Boot, Params, State, Message, IntoTask>
 where
     Params: ExtractParams,
-    Boot: Fn() -> State + Send,
+    Boot: BootFn<State, Message>,
     State: Send,
     Message: AnyMessage,
     IntoTask: Into<Task<Message>>,
 {
-    let metadata = crate::Metadata::new(label);
+    let metadata = crate::Metadata::new(label).location(crate::metadata::Location::caller());
     Stateful::new(params, boot, update_fn, view_fn, metadata)
 }
@@ -1,10 +1,12 @@
+use std::sync::Arc;
+
 use iced::{Element, Task};
 
 use crate::{
     dynamic::{ExtractParams, Param},
     message::AnyMessage,
     metadata::Metadata,
-    preview::{History, Performance, Preview},
+    preview::{Decorator, History, Performance, Preview, Variant, variant},
 };
 
 /// A dynamic stateless preview that renders an element based on adjustable parameters.
@@ -33,6 +35,10 @@ where
     cached_values: Params::Values,
     /// The view function that generates the preview from parameters.
     view_fn: F,
+    /// Named variants of this preview that share its metadata.
+    variants: Vec<Variant>,
+    /// Decorators that wrap this preview's element.
+    decorators: Vec<Decorator>,
 }
 
 impl<Data, Params, F, Message> Stateless<Data, Params, F, Message>
@@ -42,23 +48,33 @@ where
     F: for<'a> Fn(&'a Data, &'a Params::Values) -> Element<'a, Message> + Send,
     Message: AnyMessage,
 {
-    /// Add a description to the preview.
-    pub fn description(mut self, description: impl Into<String>) -> Self {
-        self.metadata = self.metadata.description(description);
+    metadata_builder_methods!();
+
+    /// Add a [decorator](Preview::take_decorators) that wraps this preview's element.
+    pub fn decorator<D>(mut self, decorator: D) -> Self
+    where
+        D: for<'a> Fn(Element<'a, crate::Message>) -> Element<'a, crate::Message>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.decorators.push(Arc::new(decorator));
         self
     }
 
-    /// Add a group to the preview.
-    pub fn group(mut self, group: impl Into<String>) -> Self {
-        self.metadata = self.metadata.group(group);
-        self
-    }
-
-    /// Add tags to the preview.
-    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
-        self.metadata = self
-            .metadata
-            .tags(tags.into_iter().map(Into::into).collect());
+    /// Add a named [variant](Preview::take_variants) with different default `params`.
+    pub fn variant(mut self, name: impl Into<String>, params: Params) -> Self
+    where
+        Data: Clone,
+        F: Clone + 'static,
+    {
+        let data = self.data.clone();
+        let view_fn = self.view_fn.clone();
+        self.variants.push(Variant::new(name, move |metadata, ()| {
+            let mut preview = stateless_with(metadata.label.clone(), data, params, view_fn);
+            preview.metadata = metadata;
+            Box::new(preview)
+        }));
         self
     }
 }
@@ -74,6 +90,10 @@ where
         &self.metadata
     }
 
+    fn metadata_mut(&mut self) -> &mut crate::Metadata {
+        &mut self.metadata
+    }
+
     fn update(&mut self, message: crate::Message) -> Task<crate::Message> {
         match message {
             crate::Message::Component(boxed) => {
@@ -126,11 +146,20 @@ where
     fn performance(&self) -> Option<&Performance> {
         Some(&self.performance)
     }
+
+    fn take_variants(&mut self) -> Vec<Box<dyn Preview>> {
+        variant::build_all(std::mem::take(&mut self.variants), &self.metadata, ())
+    }
+
+    fn take_decorators(&mut self) -> Vec<Decorator> {
+        std::mem::take(&mut self.decorators)
+    }
 }
 
 /// Create a new dynamic stateless preview with the given label, parameters, and view function.
 ///
 /// This is a convenience wrapper around [`stateless_with`] that doesn't require external data.
+#[track_caller]
 pub fn stateless<Params, F, Message>(
     label: impl Into<String>,
     params: Params,
@@ -179,6 +208,7 @@ where
 ///     })
 /// }
 /// ```
+#[track_caller]
 pub fn stateless_with<Data, Params, F, Message>(
     label: impl Into<String>,
     data: Data,
@@ -191,7 +221,7 @@ where
     F: for<'a> Fn(&'a Data, &'a Params::Values) -> Element<'a, Message> + Send,
     Message: AnyMessage,
 {
-    let metadata = crate::Metadata::new(label);
+    let metadata = crate::Metadata::new(label).location(crate::metadata::Location::caller());
     let cached_params = params.to_params();
     let cached_values = params.extract();
     Stateless {
@@ -204,5 +234,7 @@ where
         cached_params,
         cached_values,
         view_fn,
+        variants: Vec::new(),
+        decorators: Vec::new(),
     }
 }
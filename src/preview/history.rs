@@ -1,4 +1,9 @@
-use crate::{message::AnyMessage, preview::Timeline};
+use std::fmt::Display;
+
+use crate::{
+    message::{AnyClone, AnyMessage},
+    preview::Timeline,
+};
 
 /// A history of messages emitted by a preview.
 #[derive(Debug, Clone, Default)]
@@ -92,8 +97,84 @@ where
     pub fn timeline(&self) -> Timeline {
         Timeline::new(self.position as u32, self.messages.len() as u32)
     }
+
+    /// Copies the messages without their type, so they can be replayed by the
+    /// preview that replaces this one after a hot reload.
+    pub fn to_replay(&self) -> Replay {
+        Replay {
+            messages: self
+                .messages
+                .iter()
+                .map(|message| Box::new(message.clone()) as Box<dyn AnyClone>)
+                .collect(),
+            position: self.position,
+        }
+    }
+
+    /// The messages of the `replay` that still have this history's message type.
+    ///
+    /// Messages after one that no longer downcast are skipped as well, since they
+    /// were emitted from a state that can't be reproduced.
+    pub fn downcast(replay: &Replay) -> (Vec<Message>, Result<(), ReplayError>) {
+        let mut messages = Vec::with_capacity(replay.messages.len());
+        for (index, message) in replay.messages.iter().enumerate() {
+            match (**message).as_any().downcast_ref::<Message>() {
+                Some(message) => messages.push(message.clone()),
+                None => {
+                    return (
+                        messages,
+                        Err(ReplayError {
+                            index,
+                            total: replay.messages.len(),
+                        }),
+                    );
+                }
+            }
+        }
+        (messages, Ok(()))
+    }
+}
+
+/// The messages a preview emitted without their type, along with the position of its timeline.
+pub struct Replay {
+    /// The emitted messages in the order they were emitted.
+    pub messages: Vec<Box<dyn AnyClone>>,
+    /// The position of the timeline when the messages were copied.
+    pub position: usize,
+}
+
+impl std::fmt::Debug for Replay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Replay")
+            .field("messages", &self.messages.len())
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+/// An error when a message can't be replayed because its type changed, e.g. after a hot reload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayError {
+    /// The index of the first message that couldn't be replayed.
+    pub index: usize,
+    /// The total number of messages that were meant to be replayed.
+    pub total: usize,
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "replayed {} of {} messages, message {} no longer has the preview's message type",
+            self.index,
+            self.total,
+            self.index + 1
+        )
+    }
 }
 
+impl std::error::Error for ReplayError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,4 +207,24 @@ mod tests {
         history.change_position(5); // Out of bounds
         assert_eq!(history.position, 1); // Position should not change
     }
+
+    /// Replayed messages are downcast up to the first one whose type changed.
+    #[test]
+    fn downcasts_replay_until_type_changes() {
+        let mut history = History::new();
+        history.push(1);
+        history.push(2);
+        history.change_position(1);
+
+        let replay = history.to_replay();
+        assert_eq!(replay.position, 1);
+        assert_eq!(History::<i32>::downcast(&replay), (vec![1, 2], Ok(())));
+
+        let mut replay = history.to_replay();
+        replay.messages.insert(1, Box::new("changed"));
+        assert_eq!(
+            History::<i32>::downcast(&replay),
+            (vec![1], Err(ReplayError { index: 1, total: 3 }))
+        );
+    }
 }
@@ -1,32 +1,45 @@
+use std::sync::Arc;
+
 use crate::{
     Metadata, Preview,
     message::AnyMessage,
-    preview::{History, Performance, Timeline},
+    preview::{
+        BootFn, Decorator, History, Performance, Replay, ReplayError, SubscriptionFn, Timeline,
+        Variant, variant,
+    },
 };
-use iced::{Element, Task};
+use iced::{Element, Subscription, Task};
 
 /// A stateful preview with full update/view cycle.
 pub struct Stateful<Boot, State, Message, IntoTask>
 where
-    Boot: Fn() -> State,
+    Boot: BootFn<State, Message>,
     State: Send,
     Message: AnyMessage,
     IntoTask: Into<Task<Message>>,
 {
     boot: Boot,
     state: State,
+    /// The task returned by `boot` that runs once the preview is first selected.
+    boot_task: Option<Task<Message>>,
     /// The history of messages emitted by the preview.
     history: History<Message>,
     /// Performance metrics for tracking view/update function execution times.
     performance: Performance,
     update_fn: fn(&mut State, Message) -> IntoTask,
     view_fn: fn(&State) -> Element<'_, Message>,
+    /// The optional function returning the subscriptions of the current state.
+    subscription_fn: Option<SubscriptionFn<State, Message>>,
     pub(crate) metadata: Metadata,
+    /// Named variants of this preview that share its metadata and subscription.
+    variants: Vec<Variant<Option<SubscriptionFn<State, Message>>>>,
+    /// Decorators that wrap this preview's element.
+    decorators: Vec<Decorator>,
 }
 
 impl<Boot, State, Message, IntoTask> Stateful<Boot, State, Message, IntoTask>
 where
-    Boot: Fn() -> State + Send,
+    Boot: BootFn<State, Message>,
     State: Send,
     Message: AnyMessage,
     IntoTask: Into<Task<Message>>,
@@ -37,42 +50,64 @@ where
         view_fn: fn(&State) -> Element<'_, Message>,
         metadata: Metadata,
     ) -> Self {
-        let state = boot();
+        let (state, boot_task) = boot.boot();
         Self {
             boot,
             state,
+            boot_task: Some(boot_task),
             history: History::new(),
             performance: Performance::default(),
             update_fn,
             view_fn,
+            subscription_fn: None,
             metadata,
+            variants: Vec::new(),
+            decorators: Vec::new(),
         }
     }
 
-    /// Add a description to the preview.
-    pub fn description(mut self, description: impl Into<String>) -> Self {
-        self.metadata = self.metadata.description(description);
+    metadata_builder_methods!();
+
+    /// Add a subscription function, e.g. to listen to timers or keyboard events.
+    /// The subscription is only active while the preview is selected or being recorded.
+    pub fn subscription(mut self, subscription_fn: fn(&State) -> Subscription<Message>) -> Self {
+        self.subscription_fn = Some(subscription_fn);
         self
     }
 
-    /// Add a group to the preview.
-    pub fn group(mut self, group: impl Into<String>) -> Self {
-        self.metadata = self.metadata.group(group);
+    /// Add a [decorator](Preview::take_decorators) that wraps this preview's element.
+    pub fn decorator<D>(mut self, decorator: D) -> Self
+    where
+        D: for<'a> Fn(Element<'a, crate::Message>) -> Element<'a, crate::Message>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.decorators.push(Arc::new(decorator));
         self
     }
 
-    /// Add tags to the preview.
-    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
-        self.metadata = self
-            .metadata
-            .tags(tags.into_iter().map(Into::into).collect());
+    /// Add a named [variant](Preview::take_variants) that starts from the state returned by `boot`.
+    pub fn variant<VariantBoot>(mut self, name: impl Into<String>, boot: VariantBoot) -> Self
+    where
+        VariantBoot: BootFn<State, Message> + 'static,
+        State: 'static,
+        IntoTask: 'static,
+    {
+        let (update_fn, view_fn) = (self.update_fn, self.view_fn);
+        self.variants
+            .push(Variant::new(name, move |metadata, subscription_fn| {
+                let mut preview = Stateful::new(boot, update_fn, view_fn, metadata);
+                preview.subscription_fn = subscription_fn;
+                Box::new(preview)
+            }));
         self
     }
 }
 
 impl<Boot, State, Message, IntoTask> Preview for Stateful<Boot, State, Message, IntoTask>
 where
-    Boot: Fn() -> State + Send,
+    Boot: BootFn<State, Message>,
     State: Send,
     Message: AnyMessage,
     IntoTask: Into<Task<Message>>,
@@ -81,6 +116,10 @@ where
         &self.metadata
     }
 
+    fn metadata_mut(&mut self) -> &mut crate::Metadata {
+        &mut self.metadata
+    }
+
     fn update(&mut self, message: crate::Message) -> Task<crate::Message> {
         match message {
             crate::Message::Component(boxed) => {
@@ -105,14 +144,16 @@ where
                 task.map(|message| crate::Message::Component(Box::new(message)))
             }
             crate::Message::ResetPreview => {
-                self.state = (self.boot)();
+                let (state, task) = self.boot.boot();
+                self.state = state;
+                self.boot_task = None;
                 self.history.reset();
                 self.performance.reset();
-                Task::none()
+                task.map(|message| crate::Message::Component(Box::new(message)))
             }
             crate::Message::TimeTravel(index) => {
                 self.history.change_position(index as usize);
-                self.state = (self.boot)();
+                self.state = self.boot.boot().0;
                 self.history
                     .messages
                     .iter()
@@ -143,6 +184,19 @@ where
             .record_view(|| (self.view_fn)(&self.state).map(crate::Message::component))
     }
 
+    fn subscription(&self) -> Subscription<crate::Message> {
+        match self.subscription_fn {
+            Some(subscription_fn) => subscription_fn(&self.state).map(crate::Message::component),
+            None => Subscription::none(),
+        }
+    }
+
+    fn take_boot_task(&mut self) -> Task<crate::Message> {
+        self.boot_task.take().map_or_else(Task::none, |task| {
+            task.map(|message| crate::Message::Component(Box::new(message)))
+        })
+    }
+
     fn message_count(&self) -> usize {
         self.history.len()
     }
@@ -155,11 +209,44 @@ where
         Some(self.history.timeline())
     }
 
+    fn to_replay(&self) -> Option<Replay> {
+        Some(self.history.to_replay())
+    }
+
+    fn replay(&mut self, replay: Replay) -> Result<(), ReplayError> {
+        let (messages, result) = History::downcast(&replay);
+        // The boot task's messages are part of the history that's being replayed.
+        if !messages.is_empty() {
+            self.boot_task = None;
+        }
+        for message in messages {
+            self.history.push(message.clone());
+            _ = (self.update_fn)(&mut self.state, message);
+        }
+        if result.is_ok() && replay.position < self.history.len() {
+            _ = self.update(crate::Message::TimeTravel(replay.position as u32));
+        }
+        result
+    }
+
     fn performance(&self) -> Option<&Performance> {
         Some(&self.performance)
     }
+
+    fn take_variants(&mut self) -> Vec<Box<dyn Preview>> {
+        variant::build_all(
+            std::mem::take(&mut self.variants),
+            &self.metadata,
+            self.subscription_fn,
+        )
+    }
+
+    fn take_decorators(&mut self) -> Vec<Decorator> {
+        std::mem::take(&mut self.decorators)
+    }
 }
 
+#[track_caller]
 pub fn stateful<Boot, State, Message, IntoTask>(
     label: impl Into<String>,
     boot: Boot,
@@ -167,11 +254,11 @@ pub fn stateful<Boot, State, Message, IntoTask>(
     view_fn: fn(&State) -> Element<'_, Message>,
 ) -> Stateful<Boot, State, Message, IntoTask>
 where
-    Boot: Fn() -> State + Send,
+    Boot: BootFn<State, Message>,
     State: Send,
     Message: AnyMessage,
     IntoTask: Into<Task<Message>>,
 {
-    let metadata = crate::Metadata::new(label);
+    let metadata = crate::Metadata::new(label).location(crate::metadata::Location::caller());
     Stateful::new(boot, update_fn, view_fn, metadata)
 }
@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use crate::{
     Metadata, Preview,
     message::AnyMessage,
-    preview::{History, Performance},
+    preview::{Decorator, History, Performance, Variant, variant},
 };
 use iced::{Element, Task};
 
@@ -26,6 +28,10 @@ where
     performance: Performance,
     /// Metadata about the this preview.
     pub(crate) metadata: Metadata,
+    /// Named variants of this preview that share its metadata.
+    variants: Vec<Variant>,
+    /// Decorators that wrap this preview's element.
+    decorators: Vec<Decorator>,
 }
 
 impl<Data, F, Message> Stateless<Data, F, Message>
@@ -41,26 +47,33 @@ where
             history: History::new(),
             performance: Performance::default(),
             metadata,
+            variants: Vec::new(),
+            decorators: Vec::new(),
         }
     }
 
-    /// Add a description to the preview.
-    pub fn description(mut self, description: impl Into<String>) -> Self {
-        self.metadata = self.metadata.description(description);
-        self
-    }
+    metadata_builder_methods!();
 
-    /// Add a group to the preview.
-    pub fn group(mut self, group: impl Into<String>) -> Self {
-        self.metadata = self.metadata.group(group);
+    /// Add a [decorator](Preview::take_decorators) that wraps this preview's element.
+    pub fn decorator<D>(mut self, decorator: D) -> Self
+    where
+        D: for<'a> Fn(Element<'a, crate::Message>) -> Element<'a, crate::Message>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.decorators.push(Arc::new(decorator));
         self
     }
 
-    /// Add tags to the preview.
-    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
-        self.metadata = self
-            .metadata
-            .tags(tags.into_iter().map(Into::into).collect());
+    /// Add a named [variant](Preview::take_variants) that renders `view_fn`.
+    pub fn variant<G>(mut self, name: impl Into<String>, view_fn: G) -> Self
+    where
+        G: Fn() -> Element<'static, Message> + Send + 'static,
+    {
+        self.variants.push(Variant::new(name, move |metadata, ()| {
+            Box::new(Stateless::new((), move |_| view_fn(), metadata))
+        }));
         self
     }
 }
@@ -75,6 +88,10 @@ where
         &self.metadata
     }
 
+    fn metadata_mut(&mut self) -> &mut crate::Metadata {
+        &mut self.metadata
+    }
+
     fn update(&mut self, message: crate::Message) -> Task<crate::Message> {
         match message {
             crate::Message::Component(boxed) => {
@@ -107,6 +124,14 @@ where
     fn performance(&self) -> Option<&Performance> {
         Some(&self.performance)
     }
+
+    fn take_variants(&mut self) -> Vec<Box<dyn Preview>> {
+        variant::build_all(std::mem::take(&mut self.variants), &self.metadata, ())
+    }
+
+    fn take_decorators(&mut self) -> Vec<Decorator> {
+        std::mem::take(&mut self.decorators)
+    }
 }
 
 /// Create a new stateless preview with a simple view function.
@@ -128,6 +153,7 @@ where
 ///     })
 /// }
 /// ```
+#[track_caller]
 pub fn stateless<F, Message>(
     label: impl Into<String>,
     view_fn: F,
@@ -136,7 +162,7 @@ where
     Message: AnyMessage,
     F: Fn() -> Element<'static, Message> + Send + 'static,
 {
-    let metadata = crate::Metadata::new(label);
+    let metadata = crate::Metadata::new(label).location(crate::metadata::Location::caller());
     Stateless::new((), move |_| view_fn(), metadata)
 }
 
@@ -165,6 +191,7 @@ where
 ///     column(items.iter().map(|label| text(label).into())).into()
 /// }
 /// ```
+#[track_caller]
 pub fn stateless_with<Data, F, Message>(
     label: impl Into<String>,
     data: Data,
@@ -175,6 +202,6 @@ where
     Data: Send + 'static,
     F: Fn(&Data) -> Element<'_, Message> + Send + 'static,
 {
-    let metadata = crate::Metadata::new(label);
+    let metadata = crate::Metadata::new(label).location(crate::metadata::Location::caller());
     Stateless::new(data, view_fn, metadata)
 }
@@ -0,0 +1,47 @@
+use crate::{Metadata, Preview};
+
+/// A function that builds a variant's preview from the metadata and
+/// any `Shared` configuration it shares with its parent.
+type BuildFn<Shared> = Box<dyn FnOnce(Metadata, Shared) -> Box<dyn Preview> + Send>;
+
+/// A named variant of a preview, e.g. the "Disabled" state of a "Button" preview.
+///
+/// Variants are built lazily when the parent preview is registered so they share
+/// the parent's final [`Metadata`] and `Shared` configuration, regardless of the
+/// order builder methods are called in.
+pub(crate) struct Variant<Shared = ()> {
+    /// The name of the variant.
+    name: String,
+    /// Builds the variant's preview from the shared metadata and configuration.
+    build: BuildFn<Shared>,
+}
+
+impl<Shared> Variant<Shared> {
+    /// Creates a new [`Variant`] with the given `name` and `build` function.
+    pub fn new(
+        name: impl Into<String>,
+        build: impl FnOnce(Metadata, Shared) -> Box<dyn Preview> + Send + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            build: Box::new(build),
+        }
+    }
+
+    /// Builds the variant's preview using a copy of the `parent` metadata and `shared` configuration.
+    pub fn build(self, parent: &Metadata, shared: Shared) -> Box<dyn Preview> {
+        (self.build)(parent.clone().variant(self.name), shared)
+    }
+}
+
+/// Builds all `variants` using the `parent` metadata and `shared` configuration.
+pub(crate) fn build_all<Shared: Clone>(
+    variants: Vec<Variant<Shared>>,
+    parent: &Metadata,
+    shared: Shared,
+) -> Vec<Box<dyn Preview>> {
+    variants
+        .into_iter()
+        .map(|variant| variant.build(parent, shared.clone()))
+        .collect()
+}
@@ -0,0 +1,69 @@
+//! Previews registered with the [`preview`](macro@crate::preview) attribute.
+//!
+//! This module supports the code generated by the attribute and isn't meant to be used directly.
+
+use crate::{App, metadata::Location, preview::Preview};
+
+pub use inventory;
+
+/// A preview registered with the [`preview`](macro@crate::preview) attribute.
+pub struct Registration {
+    /// The module that the preview function is defined in.
+    module: &'static str,
+    /// The line that the preview function is defined on.
+    line: u32,
+    /// Adds the preview to the app.
+    register: fn(App) -> App,
+}
+
+impl Registration {
+    /// Creates a new registration that adds a preview to the app with `register`.
+    pub const fn new(module: &'static str, line: u32, register: fn(App) -> App) -> Self {
+        Self {
+            module,
+            line,
+            register,
+        }
+    }
+}
+
+inventory::collect!(Registration);
+
+/// Adds every registered preview to the `app`, ordered by the module and line they're defined on.
+pub(crate) fn register_all(app: App) -> App {
+    let mut registrations: Vec<&Registration> = inventory::iter::<Registration>().collect();
+    registrations.sort_by_key(|registration| (registration.module, registration.line));
+    registrations
+        .into_iter()
+        .fold(app, |app, registration| (registration.register)(app))
+}
+
+/// Applies the arguments and doc comment of the [`preview`](macro@crate::preview) attribute
+/// to the metadata of the `preview`.
+///
+/// The `group` replaces the group of the preview and the `tags` are added to its tags.
+/// The `description` is only used if the preview doesn't already have one.
+/// The `location` of the attribute replaces where the preview was created,
+/// so the Code tab shows the entire function.
+pub fn configure<P: Preview>(
+    mut preview: P,
+    group: Option<&str>,
+    tags: &[&str],
+    description: Option<&str>,
+    location: Location,
+) -> P {
+    let metadata = preview.metadata_mut();
+    metadata.location = Some(location);
+    if let Some(group) = group {
+        metadata.group = Some(group.to_string());
+    }
+    for tag in tags {
+        if !metadata.tags.iter().any(|existing| existing == tag) {
+            metadata.tags.push(tag.to_string());
+        }
+    }
+    if metadata.description.is_none() {
+        metadata.description = description.map(str::to_string);
+    }
+    preview
+}
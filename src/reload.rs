@@ -0,0 +1,24 @@
+//! Notifies the application when iced hot reloads its code.
+
+use std::sync::Arc;
+
+use iced::Subscription;
+use iced::futures::channel::mpsc;
+use iced::futures::{Stream, StreamExt};
+
+use crate::Message;
+
+/// Emits [`Message::HotReload`] whenever a patch of the code is applied.
+pub fn hot_reloads() -> Subscription<Message> {
+    Subscription::run(patches)
+}
+
+/// A stream of the patches applied to the code.
+fn patches() -> impl Stream<Item = Message> {
+    let (sender, receiver) = mpsc::unbounded();
+    subsecond::register_handler(Arc::new(move || {
+        // The application has shut down if the receiver was dropped.
+        let _ = sender.unbounded_send(());
+    }));
+    receiver.map(|()| Message::HotReload)
+}
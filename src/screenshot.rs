@@ -7,14 +7,25 @@
 //! ```bash
 //! cargo run -- --screenshot "Button" --output ./screenshot.png
 //! cargo run -- --screenshot "Button"  # saves to ./screenshots/button.png
+//! cargo run -- --screenshot "Forms/Inputs/TextField"  # saves to ./screenshots/forms/inputs/textfield.png
 //! cargo run -- --help  # shows usage
 //! ```
 
 use iced::theme::Base;
-use iced::{Size, Theme};
+use iced::{Size, Theme, Vector};
 use iced_test::Simulator;
+use iced_test::simulator::Snapshot;
 use std::path::{Path, PathBuf};
 
+use crate::canvas::{self, Background, Zoom};
+use crate::config_tab::ConfigTab;
+use crate::dynamic::appearance;
+use crate::preview::Descriptor;
+use crate::test::discovery::preview_folder;
+
+/// The size of the viewport that screenshots are captured at by default.
+pub const DEFAULT_SIZE: Size = Size::new(800.0, 600.0);
+
 /// Parsed screenshot options from CLI args.
 #[derive(Debug, Clone)]
 pub struct Options {
@@ -26,15 +37,34 @@ pub struct Options {
     pub theme: Theme,
     /// Viewport size.
     pub viewport_size: Size,
+    /// Zoom level of the preview.
+    pub zoom: Zoom,
+    /// Background drawn behind the preview.
+    pub background: Background,
+}
+
+/// Options for launching the GUI parsed from CLI args.
+#[derive(Debug, Clone, Default)]
+pub struct GuiOptions {
+    /// Name of the preview to select.
+    pub preview: Option<String>,
+    /// Dynamic parameter values to apply to the selected preview as `(name, value)` pairs.
+    pub params: Vec<(String, String)>,
+    /// Theme to use instead of the system theme.
+    pub theme: Option<Theme>,
+    /// Name of the configuration tab to open, e.g. `Parameters`.
+    pub tab: Option<String>,
 }
 
 /// Result of parsing command-line arguments.
 #[derive(Debug)]
 pub enum ParseResult {
-    /// Run in normal GUI mode.
-    RunGui,
+    /// Run in normal GUI mode with the given options.
+    RunGui(GuiOptions),
     /// Show help message.
     ShowHelp,
+    /// Print a machine-readable listing of the previews.
+    List,
     /// Capture a screenshot with the given options.
     Screenshot(Options),
     /// Parse error with message.
@@ -52,39 +82,28 @@ pub fn parse_args() -> ParseResult {
         return ParseResult::ShowHelp;
     }
 
+    // Check for --list
+    if args.iter().any(|a| a == "--list") {
+        return ParseResult::List;
+    }
+
     // Check for --screenshot <name>
     let Some(preview) = parse_arg(&args, "--screenshot") else {
-        return ParseResult::RunGui;
+        return parse_gui_args(&args);
     };
 
     // Parse --output (optional)
     let output = parse_arg(&args, "--output").map(PathBuf::from);
 
     // Parse --theme
-    let theme = if let Some(theme_name) = parse_arg(&args, "--theme") {
-        // Find theme by name (case-insensitive)
-        Theme::ALL
-            .iter()
-            .find(|t| t.name().to_lowercase() == theme_name.to_lowercase())
-            .cloned()
-            .unwrap_or_else(|| {
-                eprintln!(
-                    "Warning: Theme '{}' not found, using Light theme",
-                    theme_name
-                );
-                eprintln!(
-                    "Available themes: {}",
-                    Theme::ALL
-                        .iter()
-                        .map(|t| t.name())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                );
+    let theme = parse_arg(&args, "--theme")
+        .map(|name| {
+            parse_theme(&name).unwrap_or_else(|| {
+                eprintln!("Using Light theme instead");
                 Theme::Light
             })
-    } else {
-        Theme::Light
-    };
+        })
+        .unwrap_or(Theme::Light);
 
     // Parse --size (format: WIDTHxHEIGHT)
     let viewport_size = parse_arg(&args, "--size")
@@ -92,16 +111,114 @@ pub fn parse_args() -> ParseResult {
             let (w, h) = s.split_once('x')?;
             Some(Size::new(w.parse().ok()?, h.parse().ok()?))
         })
-        .unwrap_or(Size::new(800.0, 600.0));
+        .unwrap_or(DEFAULT_SIZE);
+
+    // Parse --zoom (format: fit, 150 or 150%)
+    let zoom = match parse_arg(&args, "--zoom") {
+        Some(zoom) => match Zoom::parse(&zoom) {
+            Some(zoom) => zoom,
+            None => {
+                return ParseResult::Error(format!(
+                    "Invalid zoom '{zoom}', expected 'fit' or a percentage between {}% and {}%",
+                    Zoom::MIN,
+                    Zoom::MAX
+                ));
+            }
+        },
+        // Screenshots are taken at the preview's actual size unless asked to fit.
+        None => Zoom::Percent(100),
+    };
+
+    // Parse --background (format: theme, checkerboard or a hex color)
+    let background = match parse_arg(&args, "--background") {
+        Some(background) => match Background::parse(&background) {
+            Some(background) => background,
+            None => {
+                return ParseResult::Error(format!(
+                    "Invalid background '{background}', expected 'theme', 'checkerboard' or a hex color"
+                ));
+            }
+        },
+        None => Background::default(),
+    };
 
     ParseResult::Screenshot(Options {
         preview,
         output,
         theme,
         viewport_size,
+        zoom,
+        background,
     })
 }
 
+/// Parses the options for launching the GUI.
+fn parse_gui_args(args: &[String]) -> ParseResult {
+    // Parse --param (format: Name=Value, may be repeated)
+    let mut params = Vec::new();
+    for param in parse_all_args(args, "--param") {
+        let Some((name, value)) = param.split_once('=') else {
+            return ParseResult::Error(format!(
+                "Invalid param '{param}', expected the format 'Name=Value'"
+            ));
+        };
+        params.push((name.trim().to_owned(), value.to_owned()));
+    }
+
+    // Parse --tab (case-insensitive)
+    let tab = match parse_arg(args, "--tab") {
+        Some(tab) => match ConfigTab::ALL
+            .iter()
+            .find(|t| t.name().eq_ignore_ascii_case(&tab))
+        {
+            Some(tab) => Some(tab.name().to_owned()),
+            None => {
+                return ParseResult::Error(format!(
+                    "Invalid tab '{tab}', expected one of: {}",
+                    ConfigTab::ALL.map(|t| t.name()).join(", ")
+                ));
+            }
+        },
+        None => None,
+    };
+
+    ParseResult::RunGui(GuiOptions {
+        preview: parse_arg(args, "--preview"),
+        params,
+        // An unknown theme falls back to the system theme.
+        theme: parse_arg(args, "--theme").and_then(|name| parse_theme(&name)),
+        tab,
+    })
+}
+
+/// Finds a theme by name (case-insensitive), warning about unknown themes.
+fn parse_theme(name: &str) -> Option<Theme> {
+    let theme = appearance::parse_theme(name);
+
+    if theme.is_none() {
+        eprintln!("Warning: Theme '{name}' not found");
+        eprintln!(
+            "Available themes: {}",
+            Theme::ALL
+                .iter()
+                .map(|t| t.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    theme
+}
+
+/// Parses every value of a --key that may be repeated from args.
+fn parse_all_args(args: &[String], key: &str) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter(|(_, a)| *a == key)
+        .filter_map(|(i, _)| args.get(i + 1))
+        .cloned()
+        .collect()
+}
+
 /// Parses a --key value pair from args.
 fn parse_arg(args: &[String], key: &str) -> Option<String> {
     args.iter()
@@ -118,16 +235,28 @@ USAGE:
     <binary> [OPTIONS]
 
 OPTIONS:
+    --preview <name>      Select the specified preview when launching the GUI
+    --param <name=value>  Set a parameter of the selected preview (may be repeated)
+    --tab <name>          Open a configuration tab, e.g. Parameters or Tests
+    --list                List the previews and their metadata as JSON
     --screenshot <name>   Capture a screenshot of the specified preview
-    --output <path>       Output path for PNG (default: ./screenshots/<name>.png)
-    --theme <name>        Theme for rendering (default: Light)
+    --output <path>       Output path for PNG (default: ./screenshots/<groups>/<name>.png)
+    --theme <name>        Theme for the GUI or rendering (default: system theme or Light)
     --size <WxH>          Viewport size (default: 800x600)
+    --zoom <level>        Zoom level: fit or 50-400% (default: 100%)
+    --background <bg>     Background: theme, checkerboard, or a hex color (default: theme)
     -h, --help            Show this help message
 
 EXAMPLES:
     # Launch GUI
     cargo run
 
+    # Launch GUI on a preview with some parameters set
+    cargo run -- --preview "Card" --param "Title=Hello" --param "Elevated=true" --tab Parameters
+
+    # List previews as JSON
+    cargo run -- --list > previews.json
+
     # Capture screenshot
     cargo run -- --screenshot "My Button" --output ./button
 
@@ -139,6 +268,15 @@ EXAMPLES:
 
     # Capture with Dracula theme
     cargo run -- --screenshot "Card" --theme Dracula
+
+    # Capture a small component zoomed in on a checkerboard
+    cargo run -- --screenshot "Icon" --zoom 400% --background checkerboard
+
+    # Capture a named variant of a preview
+    cargo run -- --screenshot "Button/Disabled"
+
+    # Capture a preview by its group path (saves to ./screenshots/forms/inputs/textfield)
+    cargo run -- --screenshot "Forms/Inputs/TextField"
 "#
 }
 
@@ -174,29 +312,90 @@ impl From<iced_test::Error> for Error {
     }
 }
 
-/// Captures a screenshot of the specified preview.
-pub fn capture(app: &crate::App, options: &Options) -> Result<PathBuf, Error> {
-    let descriptors = app.descriptors();
-
-    // Find the preview by name (case-insensitive, partial match)
-    let preview_index = descriptors
-        .iter()
-        .position(|d| {
-            let label = &d.metadata().label;
-            label
+/// Finds the index of a preview by `name` (case-insensitive, partial match),
+/// preferring a preview whose name matches exactly.
+///
+/// Variants are referred to by their qualified label, e.g. `Button/Disabled`,
+/// and previews can be prefixed by their group path, e.g. `Forms/Inputs/TextField`.
+pub(crate) fn find_preview(descriptors: &[Descriptor], name: &str) -> Option<usize> {
+    let exact = descriptors.iter().position(|d| {
+        let metadata = d.metadata();
+        sanitize_name(&metadata.qualified_label()) == sanitize_name(name)
+            || sanitize_name(&metadata.path()) == sanitize_name(name)
+    });
+
+    exact.or_else(|| {
+        descriptors.iter().position(|d| {
+            d.metadata()
+                .qualified_label()
                 .to_lowercase()
-                .contains(&options.preview.to_lowercase())
-                || sanitize_name(label) == sanitize_name(&options.preview)
+                .contains(&name.to_lowercase())
         })
+    })
+}
+
+/// Captures a screenshot of the specified preview.
+pub fn capture(app: &crate::App, options: &Options) -> Result<PathBuf, Error> {
+    let preview_index = find_preview(app.descriptors(), &options.preview)
         .ok_or_else(|| Error::PreviewNotFound(options.preview.clone()))?;
 
-    let descriptor = &descriptors[preview_index];
-    let label = &descriptor.metadata().label;
+    capture_preview(app, preview_index, options)
+}
 
-    let base_output_path = options.output.clone().unwrap_or_else(|| {
-        PathBuf::from("./screenshots").join(format!("{}.png", sanitize_name(label)))
-    });
+/// Captures a screenshot of the preview at the given `preview_index`,
+/// ignoring the name of the preview in the `options`.
+pub fn capture_preview(
+    app: &crate::App,
+    preview_index: usize,
+    options: &Options,
+) -> Result<PathBuf, Error> {
+    let snapshot = snapshot_preview(app, preview_index, options)?;
+    let output_path = output_path(&app.descriptors()[preview_index], options);
+    save_snapshot(&snapshot, &output_path)
+}
 
+/// The path a screenshot of the preview is saved to, before a counter is added
+/// to avoid overwriting existing screenshots.
+pub(crate) fn output_path(descriptor: &Descriptor, options: &Options) -> PathBuf {
+    // Screenshots are nested within a folder for each segment of the preview's group path.
+    options.output.clone().unwrap_or_else(|| {
+        PathBuf::from("./screenshots")
+            .join(preview_folder(descriptor.metadata()))
+            .with_extension("png")
+    })
+}
+
+/// Renders the preview at the given `preview_index` into a snapshot.
+///
+/// This needs the preview itself, so it happens on the thread that owns the app,
+/// while the slower encoding and saving is left to [`save_snapshot`].
+pub(crate) fn snapshot_preview(
+    app: &crate::App,
+    preview_index: usize,
+    options: &Options,
+) -> Result<Snapshot, Error> {
+    let mut simulator: Simulator<crate::message::Message> = Simulator::with_size(
+        iced::Settings::default(),
+        options.viewport_size,
+        crate::widget::stage(
+            app.view_preview(preview_index),
+            canvas::Settings {
+                zoom: options.zoom,
+                background: options.background,
+                offset: Vector::ZERO,
+            },
+        ),
+    );
+
+    Ok(simulator.snapshot(&options.theme)?)
+}
+
+/// Saves the `snapshot` as a PNG file next to `base_output_path`, returning the path
+/// of the created file.
+pub(crate) fn save_snapshot(
+    snapshot: &Snapshot,
+    base_output_path: &Path,
+) -> Result<PathBuf, Error> {
     let parent_dir = base_output_path
         .parent()
         .unwrap_or_else(|| std::path::Path::new("."))
@@ -205,15 +404,8 @@ pub fn capture(app: &crate::App, options: &Options) -> Result<PathBuf, Error> {
     std::fs::create_dir_all(&parent_dir).map_err(Error::CreateDirectory)?;
 
     // Find an available filename (increments counter if file already exists)
-    let output_path = find_available_path(&base_output_path, &parent_dir)?;
-
-    let mut simulator: Simulator<crate::message::Message> = Simulator::with_size(
-        iced::Settings::default(),
-        options.viewport_size,
-        descriptor.preview.view(),
-    );
+    let output_path = find_available_path(base_output_path, &parent_dir)?;
 
-    let snapshot = simulator.snapshot(&options.theme)?;
     // Creates the screenshot file for us.
     snapshot.matches_image(&output_path)?;
 
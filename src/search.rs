@@ -0,0 +1,441 @@
+//! Structured search queries that filter and rank previews.
+//!
+//! A query is made up of whitespace-separated parts:
+//! - `button` fuzzy matches the label, variant, tags, group, status, owners,
+//!   custom fields, or description of a preview.
+//! - `tag:form` only shows previews with a tag containing `form`.
+//! - `group:inputs` only shows previews whose group path contains `inputs`.
+//! - `status:deprecated` only shows previews whose status contains `deprecated`.
+//! - `owner:design` only shows previews with an owner containing `design`.
+//! - `since:0.3` only shows previews with a custom `Since` field containing `0.3`,
+//!   as long as some preview has a `Since` field. Otherwise, it's a plain term.
+//! - A leading `-` excludes matching previews instead, e.g. `-tag:deprecated` or `-legacy`.
+
+use std::collections::HashSet;
+
+use crate::Metadata;
+
+/// How much a match in each field of the metadata is worth, so that matches in
+/// the label rank above those in tags, which rank above those in the description.
+const LABEL_WEIGHT: u32 = 4000;
+const TAG_WEIGHT: u32 = 3000;
+const GROUP_WEIGHT: u32 = 2000;
+const DESCRIPTION_WEIGHT: u32 = 1000;
+
+/// A parsed search query.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    /// Lowercase terms that are fuzzy matched against every field of the metadata.
+    terms: Vec<String>,
+    /// Filters that previews must satisfy to be shown.
+    filters: Vec<Filter>,
+}
+
+/// A filter on a single field of the metadata, e.g. `tag:form` or `-group:legacy`.
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+    field: Field,
+    /// The lowercase value that the field must contain.
+    value: String,
+    /// Whether previews that match the filter are excluded instead.
+    is_negated: bool,
+}
+
+/// The field of the metadata that a [`Filter`] applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Field {
+    Tag,
+    Group,
+    Status,
+    Owner,
+    /// A custom field with the given lowercase key.
+    Custom(String),
+    /// Any field, which is used for excluded terms such as `-legacy`.
+    Any,
+}
+
+impl Query {
+    /// Parses a search query such as `tag:form group:inputs -tag:deprecated button`.
+    ///
+    /// Parts such as `since:0.3` only filter on a custom field if one of the `previews`
+    /// has a field with that name, so terms like `Button:hover` still match as text.
+    pub fn parse<'a>(input: &str, previews: impl IntoIterator<Item = &'a Metadata>) -> Self {
+        let fields: HashSet<String> = previews
+            .into_iter()
+            .flat_map(|metadata| metadata.fields.iter())
+            .map(|(name, _)| name.to_lowercase())
+            .collect();
+
+        let mut query = Self::default();
+        for part in input.split_whitespace() {
+            let part = part.to_lowercase();
+            let (is_negated, part) = match part.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest.to_string()),
+                _ => (false, part),
+            };
+
+            let (field, value) = match part.split_once(':') {
+                Some(("tag", value)) => (Field::Tag, value.to_string()),
+                Some(("group", value)) => (Field::Group, value.to_string()),
+                Some(("status", value)) => (Field::Status, value.to_string()),
+                Some(("owner", value)) => (Field::Owner, value.to_string()),
+                Some((key, value)) if fields.contains(key) => {
+                    (Field::Custom(key.to_string()), value.to_string())
+                }
+                _ if is_negated => (Field::Any, part),
+                _ => {
+                    query.terms.push(part);
+                    continue;
+                }
+            };
+
+            if !value.is_empty() {
+                query.filters.push(Filter {
+                    field,
+                    value,
+                    is_negated,
+                });
+            }
+        }
+        query
+    }
+
+    /// Returns true if the query doesn't filter out any previews.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty() && self.filters.is_empty()
+    }
+
+    /// How relevant the preview with the given `metadata` is to the query,
+    /// or `None` if it doesn't match. Higher scores are more relevant.
+    pub fn score(&self, metadata: &Metadata) -> Option<u32> {
+        let satisfies_filters = self
+            .filters
+            .iter()
+            .all(|filter| filter.matches(metadata) != filter.is_negated);
+        if !satisfies_filters {
+            return None;
+        }
+
+        self.terms
+            .iter()
+            .map(|term| term_score(term, metadata))
+            .sum()
+    }
+
+    /// The positions of the characters in `text` that match any of the query's terms.
+    pub fn highlights(&self, text: &str) -> Vec<usize> {
+        let mut positions: Vec<usize> = self
+            .terms
+            .iter()
+            .filter_map(|term| fuzzy_match(term, text))
+            .flat_map(|(_, positions)| positions)
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+}
+
+impl Filter {
+    /// Returns true if the field of the `metadata` contains the filter's value.
+    fn matches(&self, metadata: &Metadata) -> bool {
+        let contains = |text: &str| text.to_lowercase().contains(&self.value);
+        match &self.field {
+            Field::Tag => metadata.tags.iter().any(|tag| contains(tag)),
+            Field::Group => metadata.group.as_deref().is_some_and(contains),
+            Field::Status => metadata
+                .status
+                .as_ref()
+                .is_some_and(|status| contains(status.name())),
+            Field::Owner => metadata.owners.iter().any(|owner| contains(owner)),
+            Field::Custom(key) => metadata
+                .fields
+                .iter()
+                .any(|(name, value)| name.eq_ignore_ascii_case(key) && contains(value)),
+            Field::Any => {
+                contains(&metadata.label)
+                    || metadata.variant.as_deref().is_some_and(contains)
+                    || metadata.group.as_deref().is_some_and(contains)
+                    || metadata.description.as_deref().is_some_and(contains)
+                    || metadata.tags.iter().any(|tag| contains(tag))
+                    || metadata
+                        .status
+                        .as_ref()
+                        .is_some_and(|status| contains(status.name()))
+                    || metadata.owners.iter().any(|owner| contains(owner))
+                    || metadata.fields.iter().any(|(_, value)| contains(value))
+            }
+        }
+    }
+}
+
+/// The score of the best match of a lowercase `term` within the fields of the `metadata`.
+///
+/// Descriptions are only matched by substring since fuzzy matching long text
+/// would match almost any term.
+fn term_score(term: &str, metadata: &Metadata) -> Option<u32> {
+    let fuzzy = |weight: u32, text: &str| fuzzy_match(term, text).map(|(score, _)| weight + score);
+
+    [
+        fuzzy(LABEL_WEIGHT, &metadata.label),
+        metadata
+            .variant
+            .as_deref()
+            .and_then(|variant| fuzzy(LABEL_WEIGHT, variant)),
+        metadata
+            .tags
+            .iter()
+            .filter_map(|tag| fuzzy(TAG_WEIGHT, tag))
+            .max(),
+        metadata
+            .status
+            .as_ref()
+            .and_then(|status| fuzzy(TAG_WEIGHT, status.name())),
+        metadata
+            .owners
+            .iter()
+            .filter_map(|owner| fuzzy(TAG_WEIGHT, owner))
+            .max(),
+        metadata
+            .group
+            .as_deref()
+            .and_then(|group| fuzzy(GROUP_WEIGHT, group)),
+        metadata
+            .fields
+            .iter()
+            .filter_map(|(_, value)| fuzzy(GROUP_WEIGHT, value))
+            .max(),
+        metadata
+            .description
+            .as_deref()
+            .filter(|description| description.to_lowercase().contains(term))
+            .map(|_| DESCRIPTION_WEIGHT),
+    ]
+    .into_iter()
+    .flatten()
+    .max()
+}
+
+/// Fuzzy matches a lowercase `term` against the `text`, returning a score below
+/// 1000 and the positions of the matched characters in the `text`.
+///
+/// Contiguous matches score higher than scattered ones, especially at the start of the
+/// `text` or a word. Otherwise, every character of the `term` must appear in order.
+pub fn fuzzy_match(term: &str, text: &str) -> Option<(u32, Vec<usize>)> {
+    let term: Vec<char> = term.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    if term.is_empty() || term.len() > text.len() {
+        return None;
+    }
+
+    let is_word_start = |index: usize| {
+        index == 0 || !text[index - 1].is_alphanumeric() || text[index].is_uppercase()
+    };
+
+    // Prefer a contiguous match, and one that starts a word if possible.
+    let contiguous = (0..=text.len() - term.len())
+        .filter(|&start| {
+            term.iter()
+                .zip(&text[start..])
+                .all(|(&a, &b)| eq_ignore_case(a, b))
+        })
+        .min_by_key(|&start| (!is_word_start(start), start));
+
+    if let Some(start) = contiguous {
+        let score = match start {
+            0 => 900,
+            _ if is_word_start(start) => 800,
+            _ => 700,
+        };
+        return Some((score, (start..start + term.len()).collect()));
+    }
+
+    // Otherwise, match each character in order, rewarding consecutive characters and word starts.
+    let mut positions = Vec::with_capacity(term.len());
+    let mut score: u32 = 100;
+    let mut chars = term.iter().peekable();
+    for (index, &c) in text.iter().enumerate() {
+        let Some(&&expected) = chars.peek() else {
+            break;
+        };
+
+        if eq_ignore_case(c, expected) {
+            if positions.last().is_some_and(|&last| last + 1 == index) {
+                score += 20;
+            }
+            if is_word_start(index) {
+                score += 10;
+            }
+            positions.push(index);
+            chars.next();
+        }
+    }
+
+    chars.peek().is_none().then(|| (score.min(699), positions))
+}
+
+/// Compares two characters case-insensitively.
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Splits the `text` into consecutive segments that are either all highlighted or not,
+/// given the sorted character `positions` that are highlighted.
+pub fn segments<'a>(text: &'a str, positions: &[usize]) -> Vec<(&'a str, bool)> {
+    let mut segments: Vec<(&str, bool)> = Vec::new();
+    let mut start = 0;
+    let mut is_highlighted = false;
+
+    for (index, (offset, _)) in text.char_indices().enumerate() {
+        let highlight = positions.binary_search(&index).is_ok();
+        if highlight != is_highlighted && offset > start {
+            segments.push((&text[start..offset], is_highlighted));
+            start = offset;
+        }
+        is_highlighted = highlight;
+    }
+
+    if start < text.len() {
+        segments.push((&text[start..], is_highlighted));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::Status;
+
+    fn metadata() -> Metadata {
+        Metadata::new("Text Field")
+            .group("Forms/Inputs")
+            .tags(vec![String::from("form"), String::from("input")])
+            .description("A single line of editable text.")
+    }
+
+    /// Filters, negations, and plain terms are parsed from the query.
+    #[test]
+    fn parse_structured_query() {
+        let query = Query::parse("tag:Form group:inputs -tag:deprecated Button -legacy", []);
+        assert_eq!(query.terms, ["button"]);
+        assert_eq!(
+            query.filters,
+            [
+                Filter {
+                    field: Field::Tag,
+                    value: String::from("form"),
+                    is_negated: false,
+                },
+                Filter {
+                    field: Field::Group,
+                    value: String::from("inputs"),
+                    is_negated: false,
+                },
+                Filter {
+                    field: Field::Tag,
+                    value: String::from("deprecated"),
+                    is_negated: true,
+                },
+                Filter {
+                    field: Field::Any,
+                    value: String::from("legacy"),
+                    is_negated: true,
+                },
+            ]
+        );
+        assert!(Query::parse("  ", []).is_empty());
+    }
+
+    /// Filters on tags and groups include or exclude previews.
+    #[test]
+    fn filters_include_and_exclude() {
+        let metadata = metadata();
+        assert!(
+            Query::parse("tag:form group:inputs", [])
+                .score(&metadata)
+                .is_some()
+        );
+        assert!(Query::parse("tag:button", []).score(&metadata).is_none());
+        assert!(Query::parse("-tag:input", []).score(&metadata).is_none());
+        assert!(Query::parse("-group:layout", []).score(&metadata).is_some());
+        assert!(Query::parse("-editable", []).score(&metadata).is_none());
+    }
+
+    /// Variants should be searchable by their variant name.
+    #[test]
+    fn matches_variant_name() {
+        let metadata = Metadata::new("Button").variant("Disabled");
+        assert!(Query::parse("disabled", []).score(&metadata).is_some());
+        assert!(Query::parse("loading", []).score(&metadata).is_none());
+    }
+
+    /// Filters on the status, owners, and custom fields include or exclude previews.
+    #[test]
+    fn filters_status_owners_and_fields() {
+        let metadata = metadata()
+            .status(Status::deprecated("Use TextInput"))
+            .owners(vec![String::from("Design Systems")])
+            .field("Since", "0.3");
+        let parse = |input: &str| Query::parse(input, [&metadata]);
+        assert!(parse("status:deprecated").score(&metadata).is_some());
+        assert!(parse("-status:deprecated").score(&metadata).is_none());
+        assert!(parse("owner:design").score(&metadata).is_some());
+        assert!(parse("since:0.3 field").score(&metadata).is_some());
+        assert!(parse("since:0.4").score(&metadata).is_none());
+        assert!(parse("systems").score(&metadata).is_some());
+        assert!(parse("https://example.com").filters.is_empty());
+    }
+
+    /// Matches in the label rank above matches in tags, which rank above the description.
+    #[test]
+    fn score_ranks_fields() {
+        let label = Metadata::new("Form");
+        let tag = Metadata::new("Card").tags(vec![String::from("form")]);
+        let description = Metadata::new("Card").description("Part of a form.");
+
+        let query = Query::parse("form", []);
+        let label = query.score(&label).unwrap();
+        let tag = query.score(&tag).unwrap();
+        let description = query.score(&description).unwrap();
+        assert!(label > tag && tag > description);
+    }
+
+    /// Contiguous matches at the start of a word rank above scattered ones.
+    #[test]
+    fn fuzzy_match_prefers_contiguous_matches() {
+        let (prefix, positions) = fuzzy_match("fie", "Text Field").unwrap();
+        assert_eq!(positions, [5, 6, 7]);
+
+        let (scattered, positions) = fuzzy_match("txfd", "Text Field").unwrap();
+        assert_eq!(positions, [0, 2, 5, 9]);
+        assert!(prefix > scattered);
+
+        assert!(fuzzy_match("dx", "Text Field").is_none());
+    }
+
+    /// Highlighted positions split the text into segments.
+    #[test]
+    fn segments_split_highlighted_runs() {
+        assert_eq!(
+            segments("Text Field", &[0, 1, 5]),
+            [("Te", true), ("xt ", false), ("F", true), ("ield", false)]
+        );
+        assert_eq!(segments("Card", &[]), [("Card", false)]);
+    }
+
+    /// Parts with a colon are plain terms unless some preview has a field with that name.
+    #[test]
+    fn unknown_fields_are_terms() {
+        let metadata = Metadata::new("Button")
+            .variant("Hover")
+            .field("Since", "0.3");
+        let query = Query::parse("Button:hover v1:beta", [&metadata]);
+        assert!(query.filters.is_empty());
+        assert_eq!(query.terms, ["button:hover", "v1:beta"]);
+        assert!(
+            Query::parse("since:0.3", [&metadata])
+                .score(&metadata)
+                .is_some()
+        );
+    }
+}
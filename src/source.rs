@@ -0,0 +1,440 @@
+//! The source code of previews, which is shown in the Code tab.
+
+use std::path::{Path, PathBuf};
+
+use iced::Length;
+use iced::widget::markdown;
+
+use crate::Metadata;
+use crate::dynamic::{self, Param, Value};
+use crate::metadata::Location;
+
+/// The source code of a preview, rendered with the current values of its parameters.
+#[derive(Debug)]
+pub struct Snippet {
+    /// The index of the preview that the snippet belongs to.
+    pub index: usize,
+    /// The source code as written, if it could be read.
+    source: Option<String>,
+    /// The parameters that the code was rendered with.
+    params: Vec<Param>,
+    /// The source code with the current values of the parameters.
+    pub code: Option<String>,
+    /// The Markdown items of the highlighted code block.
+    pub items: Vec<markdown::Item>,
+}
+
+impl Snippet {
+    /// Reads the source code of the preview at `index` with the given `metadata`.
+    pub fn new(index: usize, metadata: &Metadata, params: &[Param]) -> Self {
+        let mut snippet = Self {
+            index,
+            source: metadata.location.as_ref().and_then(read),
+            params: Vec::new(),
+            code: None,
+            items: Vec::new(),
+        };
+        snippet.render(params);
+        snippet
+    }
+
+    /// Renders the source code with the current values of the `params` if they've changed.
+    pub fn update(&mut self, params: &[Param]) {
+        if self.params != params {
+            self.render(params);
+        }
+    }
+
+    fn render(&mut self, params: &[Param]) {
+        self.params = params.to_vec();
+        self.code = self
+            .source
+            .as_deref()
+            .map(|source| with_param_values(source, params));
+        self.items = self
+            .code
+            .as_deref()
+            .map(|code| markdown::parse(&format!("```rust\n{code}\n```")).collect())
+            .unwrap_or_default();
+    }
+}
+
+/// Reads the code of the expression or item that starts at the `location`.
+///
+/// Locations are relative to the workspace root, so the file is looked up
+/// from the working directory and each of its parent directories.
+fn read(location: &Location) -> Option<String> {
+    let path = Path::new(location.file);
+    let candidates: Vec<PathBuf> = if path.is_absolute() {
+        vec![path.to_path_buf()]
+    } else {
+        std::env::current_dir()
+            .ok()?
+            .ancestors()
+            .map(|directory| directory.join(path))
+            .collect()
+    };
+
+    let contents = candidates
+        .iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())?;
+    extract(&contents, location.line, location.column)
+}
+
+/// Extracts the expression or item that starts at the 1-based `line` and `column`,
+/// including any chained method calls, with its indentation removed.
+fn extract(contents: &str, line: u32, column: u32) -> Option<String> {
+    let line_index = usize::try_from(line).ok()?.checked_sub(1)?;
+    let line_start: usize = contents
+        .split_inclusive('\n')
+        .take(line_index)
+        .map(str::len)
+        .sum();
+    let first_line = contents.get(line_start..)?.lines().next()?;
+    let column_offset = first_line
+        .char_indices()
+        .nth(usize::try_from(column).ok()?.checked_sub(1)?)?
+        .0;
+    let indent = first_line.len() - first_line.trim_start().len();
+
+    let rest = &contents[line_start + column_offset..];
+    let code = &rest[..expression_end(rest)?];
+
+    let mut lines = code.lines();
+    let mut dedented = vec![lines.next()?.to_string()];
+    dedented.extend(lines.map(|line| {
+        let whitespace = line.len() - line.trim_start().len();
+        line[whitespace.min(indent)..].trim_end().to_string()
+    }));
+    Some(dedented.join("\n"))
+}
+
+/// The significant bytes of Rust code and their offsets, skipping whitespace,
+/// comments, and the contents of string and character literals.
+///
+/// String and character literals are yielded as a single `"` at their start.
+fn tokens(code: &str) -> Vec<(usize, u8)> {
+    let bytes = code.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let next = bytes.get(index + 1).copied();
+        match byte {
+            b'/' if next == Some(b'/') => {
+                index = code[index..]
+                    .find('\n')
+                    .map_or(bytes.len(), |end| index + end);
+                continue;
+            }
+            b'/' if next == Some(b'*') => {
+                index = code[index + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| index + 2 + end + 2);
+                continue;
+            }
+            b'"' => {
+                tokens.push((index, b'"'));
+                index += 1;
+                while index < bytes.len() && bytes[index] != b'"' {
+                    index += if bytes[index] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'r' if matches!(next, Some(b'"' | b'#'))
+                && !index
+                    .checked_sub(1)
+                    .is_some_and(|previous| is_identifier(bytes[previous])) =>
+            {
+                let hashes = code[index + 1..]
+                    .bytes()
+                    .take_while(|&byte| byte == b'#')
+                    .count();
+                let opening = index + 1 + hashes;
+                if bytes.get(opening) != Some(&b'"') {
+                    // A raw identifier such as `r#type`.
+                    index += 1;
+                    continue;
+                }
+                tokens.push((index, b'"'));
+                let closing = format!("\"{}", "#".repeat(hashes));
+                index = code[opening + 1..]
+                    .find(&closing)
+                    .map_or(bytes.len(), |end| opening + 1 + end + closing.len() - 1);
+            }
+            b'\'' => {
+                // Character literals end with a quote, unlike lifetimes such as `'a`.
+                let end = if next == Some(b'\\') {
+                    code[index + 2..].find('\'').map(|end| index + 2 + end)
+                } else {
+                    code[index + 1..]
+                        .char_indices()
+                        .nth(1)
+                        .filter(|&(_, c)| c == '\'')
+                        .map(|(offset, _)| index + 1 + offset)
+                };
+                if let Some(end) = end {
+                    tokens.push((index, b'"'));
+                    index = end;
+                }
+            }
+            _ if byte.is_ascii_whitespace() => {}
+            _ => tokens.push((index, byte)),
+        }
+        index += 1;
+    }
+
+    tokens
+}
+
+/// Returns true if the `byte` can be part of an identifier.
+fn is_identifier(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// The offset just past the end of the expression or item at the start of the `code`.
+///
+/// The code ends when its brackets are balanced after a closing parenthesis or brace,
+/// unless it continues with a method call, return type, block, or `?`.
+fn expression_end(code: &str) -> Option<usize> {
+    let tokens = tokens(code);
+    let mut depth = 0usize;
+
+    for (position, &(index, byte)) in tokens.iter().enumerate() {
+        match byte {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => {
+                depth = depth.checked_sub(1)?;
+                let next = tokens.get(position + 1).map(|&(_, byte)| byte);
+                if depth == 0 && byte != b']' && !matches!(next, Some(b'.' | b'-' | b'{' | b'?')) {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The functions of the `dynamic` module whose last argument is the default value of a parameter.
+const PARAM_FUNCTIONS: [&str; 9] = [
+    "text",
+    "number",
+    "float",
+    "boolean",
+    "slider",
+    "color",
+    "length",
+    "padding",
+    "alignment",
+];
+
+/// Replaces the default values of the parameters in the `code` with their current values.
+///
+/// Parameters are found by calls to a `dynamic` function whose first argument is the name
+/// of the parameter, e.g. `dynamic::text("Label", "Hello")`, and the last argument of the
+/// call is replaced. Other calls with the same first argument, like the label of a preview,
+/// are left as is, along with parameters without a Rust literal for their value.
+pub fn with_param_values(code: &str, params: &[Param]) -> String {
+    let mut code = code.to_string();
+
+    for param in params {
+        let Some(literal) = literal(&param.value) else {
+            continue;
+        };
+        let name = format!("{:?}", param.name);
+
+        let mut search_start = 0;
+        while let Some(found) = code[search_start..].find(&name) {
+            let name_start = search_start + found;
+            let name_end = name_start + name.len();
+            search_start = name_end;
+
+            let Some(callee) = code[..name_start].trim_end().strip_suffix('(') else {
+                continue;
+            };
+            if !is_param_function(callee.trim_end()) {
+                continue;
+            }
+
+            if let Some(range) = last_argument(&code[name_end..]) {
+                let start = name_end + range.start;
+                let end = name_end + range.end;
+                code.replace_range(start..end, &literal);
+                search_start = start + literal.len();
+            }
+        }
+    }
+
+    code
+}
+
+/// Returns true if the `code` ends with the path of a parameter function, e.g. `dynamic::text`.
+fn is_param_function(code: &str) -> bool {
+    let path_start = code
+        .bytes()
+        .rposition(|byte| !is_identifier(byte) && byte != b':')
+        .map_or(0, |index| index + 1);
+    match code[path_start..].rsplit_once("::") {
+        Some((module, function)) => {
+            (module == "dynamic" || module.ends_with("::dynamic"))
+                && PARAM_FUNCTIONS.contains(&function)
+        }
+        None => false,
+    }
+}
+
+/// The range of the last argument of a call, given the `code` following its first argument.
+fn last_argument(code: &str) -> Option<std::ops::Range<usize>> {
+    let mut depth = 0usize;
+    let mut commas = Vec::new();
+
+    for (index, byte) in tokens(code) {
+        match byte {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' if depth > 0 => depth -= 1,
+            b')' => {
+                // Skip a trailing comma after the last argument.
+                let start = commas
+                    .iter()
+                    .rev()
+                    .map(|&comma| comma + 1)
+                    .find(|&start| !code[start..index].trim().is_empty())?;
+                let argument = &code[start..index];
+                let leading = argument.len() - argument.trim_start().len();
+                let start = start + leading;
+                return Some(start..start + argument.trim().len());
+            }
+            b',' if depth == 0 => commas.push(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// A Rust literal for the `value`, if there is one.
+fn literal(value: &Value) -> Option<String> {
+    Some(match value {
+        Value::Bool(value) => value.to_string(),
+        Value::Text(text) => format!("{text:?}"),
+        Value::I32(value) => value.to_string(),
+        Value::Float(float) => format!("{:?}", float.value),
+        Value::Slider(value, _) => format!("{value:?}"),
+        Value::Color(color) if color.a >= 1.0 => {
+            format!(
+                "Color::from_rgb({:?}, {:?}, {:?})",
+                color.r, color.g, color.b
+            )
+        }
+        Value::Color(color) => format!(
+            "Color::from_rgba({:?}, {:?}, {:?}, {:?})",
+            color.r, color.g, color.b, color.a
+        ),
+        Value::Length(length, _) => {
+            if let Length::FillPortion(portion) = length {
+                format!("Length::FillPortion({portion})")
+            } else if let Length::Fixed(pixels) = length {
+                format!("Length::Fixed({pixels:?})")
+            } else if *length == Length::Fill {
+                String::from("Length::Fill")
+            } else if *length == Length::Shrink {
+                String::from("Length::Shrink")
+            } else {
+                return None;
+            }
+        }
+        Value::Padding(sides) => {
+            let padding = dynamic::padding_from_sides(sides);
+            if padding.top == padding.bottom && padding.left == padding.right {
+                format!("[{:?}, {:?}]", padding.top, padding.left)
+            } else {
+                format!(
+                    "Padding {{ top: {:?}, right: {:?}, bottom: {:?}, left: {:?} }}",
+                    padding.top, padding.right, padding.bottom, padding.left
+                )
+            }
+        }
+        Value::Alignment(alignment) => format!("alignment::Horizontal::{alignment:?}"),
+        Value::Select(..)
+        | Value::Font(_)
+        | Value::Theme(_)
+        | Value::Optional(..)
+        | Value::List(..) => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pub fn previews(app: App) -> App {
+    app.preview(
+        dynamic::stateless(
+            "Label",
+            (dynamic::text("Content", "Hi, (friend)"), dynamic::slider("Size", 0.0..=64.0, 16.0),),
+            |(content, size)| label(content, *size), // Ignores ')'
+        )
+        .description("A label"),
+    )
+    .preview(stateless("Lifetime", || -> Element<'static, ()> { space().into() }))
+}
+"#;
+
+    /// Expressions are extracted with their chained method calls and without indentation.
+    #[test]
+    fn extracts_expressions() {
+        assert_eq!(
+            extract(SOURCE, 3, 9).as_deref(),
+            Some(
+                r#"dynamic::stateless(
+    "Label",
+    (dynamic::text("Content", "Hi, (friend)"), dynamic::slider("Size", 0.0..=64.0, 16.0),),
+    |(content, size)| label(content, *size), // Ignores ')'
+)
+.description("A label")"#
+            )
+        );
+        assert_eq!(
+            extract(SOURCE, 10, 14).as_deref(),
+            Some(r#"stateless("Lifetime", || -> Element<'static, ()> { space().into() })"#)
+        );
+    }
+
+    /// Items are extracted through their return type and body.
+    #[test]
+    fn extracts_items() {
+        let code = "#[snowscape::preview(tags = [\"a\"])]\nfn button() -> impl Preview {\n    stateless(\"Button\", view)\n}\n\nfn other() {}\n";
+        assert_eq!(
+            extract(code, 1, 1).as_deref(),
+            Some(
+                "#[snowscape::preview(tags = [\"a\"])]\nfn button() -> impl Preview {\n    stateless(\"Button\", view)\n}"
+            )
+        );
+    }
+
+    /// The default values of parameters are replaced with their current values.
+    #[test]
+    fn replaces_param_values() {
+        let code = extract(SOURCE, 3, 9).unwrap();
+        let params = [
+            Param::new("Content", String::from("Bye")),
+            Param::new("Size", Value::Slider(24.5, 0.0..=64.0)),
+        ];
+        let code = with_param_values(&code, &params);
+        assert!(code.contains(r#"dynamic::text("Content", "Bye")"#));
+        assert!(code.contains(r#"dynamic::slider("Size", 0.0..=64.0, 24.5)"#));
+    }
+
+    /// Only calls to parameter functions are changed, even if a preview has the same label.
+    #[test]
+    fn ignores_labels_named_like_params() {
+        let code = r#"stateless_with("Size", 4.0, snowscape::dynamic::number("Size", 8))"#;
+        let params = [Param::new("Size", 12)];
+        assert_eq!(
+            with_param_values(code, &params),
+            r#"stateless_with("Size", 4.0, snowscape::dynamic::number("Size", 12))"#
+        );
+    }
+}
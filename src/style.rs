@@ -128,6 +128,26 @@ pub mod button {
         }
     }
 
+    /// Toggle button style that uses the primary color while `active`.
+    pub fn toggle(theme: &Theme, status: button::Status, active: bool) -> button::Style {
+        if !active {
+            return subtle(theme, status);
+        }
+
+        let palette = theme.palette();
+        let pair = match status {
+            button::Status::Hovered => palette.primary.strong,
+            button::Status::Pressed => palette.primary.stronger,
+            _ => palette.primary.base,
+        };
+        button::Style {
+            background: Some(pair.color.into()),
+            text_color: pair.text,
+            border: border::rounded(BORDER_RADIUS),
+            ..Default::default()
+        }
+    }
+
     /// Ghost button style: transparent at rest with subtle hover/press background.
     pub fn ghost_subtle(theme: &Theme, status: button::Status) -> button::Style {
         let palette = theme.palette();
@@ -179,6 +199,43 @@ pub mod container {
         }
     }
 
+    /// A bordered canvas that previews are rendered on, e.g. in the theme matrix or viewports.
+    pub fn canvas(theme: &Theme) -> container::Style {
+        let palette = theme.palette();
+
+        container::Style {
+            text_color: Some(palette.background.base.text),
+            background: Some(palette.background.base.color.into()),
+            border: Border::default()
+                .rounded(4)
+                .width(1)
+                .color(palette.background.strong.color),
+            ..Default::default()
+        }
+    }
+
+    /// A banner that warns about something that went wrong, e.g. restoring previews after a reload.
+    pub fn warning(theme: &Theme) -> container::Style {
+        let pair = theme.palette().warning.weak;
+
+        container::Style {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A banner that confirms something went well, e.g. saving a screenshot.
+    pub fn success(theme: &Theme) -> container::Style {
+        let pair = theme.palette().success.weak;
+
+        container::Style {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            ..Default::default()
+        }
+    }
+
     pub fn dialog_backdrop(theme: &Theme, animate: bool) -> container::Style {
         let alpha = if animate { 0.5 } else { 0.6 };
 
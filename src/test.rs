@@ -11,7 +11,7 @@ pub mod message;
 pub mod outcome;
 mod runner;
 mod session;
-mod size_input;
+pub(crate) mod size_input;
 mod snapshot;
 pub mod state;
 
@@ -74,12 +74,15 @@ where
     let mut failures = Vec::new();
     let mut test_count = 0;
 
-    // Find all preview folders (directories in tests_dir)
-    let preview_folders: Vec<_> = fs::read_dir(tests_dir)
-        .map_err(Error::IoError)?
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.path().is_dir())
-        .collect();
+    // Find all preview folders, which are nested within a folder for each of their groups
+    let mut preview_folders = Vec::new();
+    for entry in fs::read_dir(tests_dir).map_err(Error::IoError)? {
+        let path = entry.map_err(Error::IoError)?.path();
+        if path.is_dir() {
+            collect_test_folders(&path, &mut preview_folders).map_err(Error::IoError)?;
+        }
+    }
+    preview_folders.sort();
 
     // Also check for legacy flat .ice files in tests_dir
     let legacy_ice_files: Vec<_> = fs::read_dir(tests_dir)
@@ -97,17 +100,21 @@ where
     }
 
     // Process each preview folder
-    for folder_entry in preview_folders {
-        let folder_path = folder_entry.path();
-        let preview_folder_name = folder_path
-            .file_name()
-            .and_then(|s| s.to_str())
-            .unwrap_or("unknown");
-
-        // Find matching preview by sanitized name
+    for folder_path in preview_folders {
+        let preview_folder = folder_path.strip_prefix(tests_dir).unwrap_or(&folder_path);
+        let preview_folder_name = preview_folder
+            .iter()
+            .filter_map(|s| s.to_str())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        // Find matching preview by its group path and sanitized name, including the variant
+        // name if any. Folders that aren't nested in groups are still supported for
+        // previews that have since been moved into a group.
         let matching_index = initial_app.descriptors().iter().position(|d| {
-            let label = &d.metadata().label;
-            discovery::sanitize_name(label) == preview_folder_name
+            let metadata = d.metadata();
+            discovery::preview_folder(metadata) == preview_folder
+                || discovery::sanitize_name(&metadata.qualified_label()) == preview_folder_name
         });
 
         let Some(preview_index) = matching_index else {
@@ -199,8 +206,8 @@ where
 
         // Find matching preview by sanitized name (legacy behavior)
         let matching_index = initial_app.descriptors().iter().position(|d| {
-            let label = &d.metadata().label;
-            discovery::sanitize_name(label) == test_name
+            let label = d.metadata().qualified_label();
+            discovery::sanitize_name(&label) == test_name
         });
 
         let Some(preview_index) = matching_index else {
@@ -231,6 +238,27 @@ where
     }
 }
 
+/// Collects the `dir` and all folders nested within it that directly contain `.ice` files.
+fn collect_test_folders(
+    dir: &std::path::Path,
+    folders: &mut Vec<std::path::PathBuf>,
+) -> std::io::Result<()> {
+    let mut has_tests = false;
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_test_folders(&path, folders)?;
+        } else if path.extension().is_some_and(|ext| ext == "ice") {
+            has_tests = true;
+        }
+    }
+
+    if has_tests {
+        folders.push(dir.to_path_buf());
+    }
+    Ok(())
+}
+
 /// Runs a single test against a preview, returning an error message if it fails.
 ///
 /// If snapshot validation is enabled, this compares against `{name}-{renderer}.png`.
@@ -250,7 +278,7 @@ fn run_single_test(
             iced_test::Simulator::with_size(
                 iced::Settings::default(),
                 ice.viewport,
-                app.descriptors()[preview_index].preview.view(),
+                app.view_preview(preview_index),
             );
 
         let snapshot = match simulator.snapshot(&iced::Theme::Light) {
@@ -336,7 +364,7 @@ pub(crate) fn capture_baseline_screenshot(
         iced_test::Simulator::with_size(
             iced::Settings::default(),
             ice.viewport,
-            app.descriptors()[preview_index].preview.view(),
+            app.view_preview(preview_index),
         );
 
     let snapshot = simulator.snapshot(&iced::Theme::Light).map_err(|e| {
@@ -404,7 +432,7 @@ fn replay_test(
     let mut simulator: Simulator<crate::message::Message> = Simulator::with_size(
         iced::Settings::default(),
         ice.viewport,
-        app.descriptors()[preview_index].preview.view(),
+        app.view_preview(preview_index),
     );
 
     // Run each instruction
@@ -436,7 +464,7 @@ fn replay_test(
                 simulator = Simulator::with_size(
                     iced::Settings::default(),
                     ice.viewport,
-                    app.descriptors()[preview_index].preview.view(),
+                    app.view_preview(preview_index),
                 );
             }
             Instruction::Expect(Expectation::Text(expected_text)) => {
@@ -2,6 +2,8 @@
 
 use std::path::{Path, PathBuf};
 
+use crate::Metadata;
+
 /// Information about a discovered test.
 #[derive(Debug, Clone)]
 pub struct TestInfo {
@@ -9,7 +11,7 @@ pub struct TestInfo {
     pub name: String,
     /// The full path to the `.ice` file.
     pub path: PathBuf,
-    /// The preview this test belongs to (folder name).
+    /// The preview this test belongs to (folder relative to the tests directory).
     pub preview: String,
     /// Whether a snapshot exists for this test.
     pub has_snapshot: bool,
@@ -40,12 +42,23 @@ fn sanitized_chars(name: &str) -> impl Iterator<Item = char> + '_ {
     })
 }
 
+/// The folder containing a preview's tests relative to the tests directory.
+///
+/// The sanitized qualified label is nested within a folder for each segment of the
+/// preview's group path, e.g. `forms/inputs/textfield-disabled`.
+pub fn preview_folder(metadata: &Metadata) -> PathBuf {
+    metadata
+        .group_path()
+        .map(sanitize_name)
+        .chain(std::iter::once(sanitize_name(&metadata.qualified_label())))
+        .collect()
+}
+
 /// Discovers all tests for a given preview in the tests directory.
 ///
-/// Tests are expected to be in `{tests_dir}/{sanitized_preview_name}/*.ice`.
-pub fn discover_tests(tests_dir: &Path, preview_name: &str) -> Vec<TestInfo> {
-    let sanitized_preview = sanitize_name(preview_name);
-    let preview_dir = tests_dir.join(&sanitized_preview);
+/// Tests are expected to be in `{tests_dir}/{preview_folder}/*.ice`, see [`preview_folder`].
+pub fn discover_tests(tests_dir: &Path, preview_folder: &Path) -> Vec<TestInfo> {
+    let preview_dir = tests_dir.join(preview_folder);
 
     if !preview_dir.exists() || !preview_dir.is_dir() {
         return Vec::new();
@@ -76,7 +89,7 @@ pub fn discover_tests(tests_dir: &Path, preview_name: &str) -> Vec<TestInfo> {
         tests.push(TestInfo {
             name: file_stem.to_string(),
             path,
-            preview: sanitized_preview.clone(),
+            preview: preview_folder.to_string_lossy().into_owned(),
             has_snapshot,
         });
     }
@@ -181,6 +194,22 @@ mod tests {
         assert_eq!(sanitize_name("A/B Test"), "a-b-test");
     }
 
+    /// Preview folders are nested within a folder for each group.
+    #[test]
+    fn preview_folder_mirrors_group_path() {
+        let metadata = Metadata::new("Text Field")
+            .group("Forms/Inputs")
+            .variant("Disabled");
+        assert_eq!(
+            preview_folder(&metadata),
+            Path::new("forms/inputs/text-field-disabled")
+        );
+        assert_eq!(
+            preview_folder(&Metadata::new("Button")),
+            Path::new("button")
+        );
+    }
+
     #[test]
     fn is_sanitized_true_for_valid_kebab_name() {
         assert!(is_sanitized("basic-increment"));
@@ -33,8 +33,8 @@ pub enum Message {
     AddTextExpectation,
     /// Removes the current test session after saving.
     RemoveSession,
-    /// Refresh the list of discovered tests for the current preview.
-    RefreshList(String),
+    /// Refresh the list of discovered tests in the given preview folder.
+    RefreshList(PathBuf),
     /// Run all tests for the current preview.
     RunAll,
     /// Run a single test by path.
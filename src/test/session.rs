@@ -14,8 +14,10 @@ pub struct Session {
     pub config: Config,
     /// The index of the preview being tested.
     pub preview_index: usize,
-    /// The name of the preview (used for folder organization).
+    /// The name of the preview, including its variant name if any.
     pub preview_name: String,
+    /// The folder of the preview's tests relative to the tests directory.
+    pub preview_folder: PathBuf,
     /// The name of the test (used for the `.ice` filename).
     pub test_name: String,
     /// Recorded interactions in Ice format.
@@ -32,12 +34,14 @@ impl Session {
         config: Config,
         preview_index: usize,
         preview_name: String,
+        preview_folder: PathBuf,
         test_name: String,
     ) -> Self {
         Self {
             config,
             preview_index,
             preview_name,
+            preview_folder,
             test_name,
             instructions: Vec::new(),
             is_recording: true,
@@ -45,11 +49,6 @@ impl Session {
         }
     }
 
-    /// Returns the sanitized preview name for folder naming.
-    pub fn sanitized_preview_name(&self) -> String {
-        sanitize_name(&self.preview_name)
-    }
-
     /// Returns the sanitized test name for file naming.
     pub fn sanitized_test_name(&self) -> String {
         sanitize_name(&self.test_name)
@@ -62,7 +61,7 @@ impl Session {
 
     /// Returns the directory for this preview's tests.
     pub fn preview_dir(&self) -> PathBuf {
-        self.config.tests_dir.join(self.sanitized_preview_name())
+        self.config.tests_dir.join(&self.preview_folder)
     }
 
     /// Returns the full path where the test file will be saved.
@@ -151,6 +150,7 @@ mod tests {
             Config::default(),
             0,
             "preview".to_string(),
+            PathBuf::from("preview"),
             "test".to_string(),
         );
 
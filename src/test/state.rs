@@ -1,6 +1,9 @@
 //! Test state management encapsulating all test-related functionality.
 
-use std::path::PathBuf;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
 
 use iced::{Task, window};
 
@@ -63,8 +66,10 @@ pub enum RunMode {
 
 /// Context needed for test operations.
 pub struct UpdateContext<'a> {
-    /// The name of the currently selected preview.
-    pub preview_name: &'a str,
+    /// The name of the currently selected preview, including its variant name if any.
+    pub preview_name: Cow<'a, str>,
+    /// The folder of the currently selected preview's tests relative to the tests directory.
+    pub preview_folder: PathBuf,
     /// The index of the currently selected preview.
     pub preview_index: usize,
     /// Callback to build a fresh app for running tests.
@@ -150,6 +155,7 @@ impl State {
                     self.config.clone(),
                     ctx.preview_index,
                     ctx.preview_name.to_string(),
+                    ctx.preview_folder,
                     test_name.to_string(),
                 );
                 self.session = Some(session);
@@ -216,24 +222,17 @@ impl State {
                 Task::none()
             }
             Message::RemoveSession => {
-                // Get the preview name before clearing session for refresh
-                let preview_name = self
-                    .session
-                    .as_ref()
-                    .map(|s| s.preview_name.clone())
-                    .unwrap_or_default();
-
-                self.session = None;
+                // Get the preview folder before clearing session for refresh
+                let preview_folder = self.session.take().map(|s| s.preview_folder);
 
                 // Refresh the test list
-                if !preview_name.is_empty() {
-                    Task::done(Message::RefreshList(preview_name))
-                } else {
-                    Task::none()
+                match preview_folder {
+                    Some(preview_folder) => Task::done(Message::RefreshList(preview_folder)),
+                    None => Task::none(),
                 }
             }
-            Message::RefreshList(preview_name) => {
-                self.discovered_tests = discover_tests(&self.config.tests_dir, &preview_name);
+            Message::RefreshList(preview_folder) => {
+                self.discovered_tests = discover_tests(&self.config.tests_dir, &preview_folder);
                 Task::none()
             }
             Message::RunAll => {
@@ -294,13 +293,12 @@ impl State {
                     eprintln!("Failed to delete test: {}", e);
                 }
 
-                // Refresh the list - we need the preview name
-                let preview_name = path
+                // Refresh the list - we need the preview folder
+                let preview_folder = path
                     .parent()
-                    .and_then(|p| p.file_name())
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("")
-                    .to_string();
+                    .and_then(|p| p.strip_prefix(&self.config.tests_dir).ok())
+                    .unwrap_or(Path::new(""))
+                    .to_path_buf();
 
                 let test_name = path
                     .file_stem()
@@ -308,7 +306,7 @@ impl State {
                     .unwrap_or("")
                     .to_string();
 
-                self.discovered_tests = discover_tests(&self.config.tests_dir, &preview_name);
+                self.discovered_tests = discover_tests(&self.config.tests_dir, &preview_folder);
                 self.remove_test_result(&test_name);
                 Task::none()
             }
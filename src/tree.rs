@@ -0,0 +1,142 @@
+//! A tree of previews built from the slash-separated group paths in their metadata.
+
+use crate::Metadata;
+
+/// A group of previews in the sidebar, which may contain nested groups.
+#[derive(Debug, Default)]
+pub struct Group<'a> {
+    /// The name of the group, e.g. `Inputs`.
+    pub name: &'a str,
+    /// The full path of the group, e.g. `Forms/Inputs`, which identifies whether it's collapsed.
+    pub path: String,
+    /// Nested groups in the order they were first registered.
+    pub groups: Vec<Group<'a>>,
+    /// The previews directly within this group along with their index.
+    pub previews: Vec<(usize, &'a Metadata)>,
+}
+
+impl<'a> Group<'a> {
+    /// Builds the root group of a tree from the `previews` and their group paths.
+    pub fn tree(previews: impl IntoIterator<Item = (usize, &'a Metadata)>) -> Self {
+        let mut root = Self::default();
+        for (index, metadata) in previews {
+            let group = metadata
+                .group_path()
+                .fold(&mut root, |group, name| group.child(name));
+            group.previews.push((index, metadata));
+        }
+        root
+    }
+
+    /// The nested group with the given `name`, which is created if it doesn't exist yet.
+    fn child(&mut self, name: &'a str) -> &mut Self {
+        let position = match self.groups.iter().position(|group| group.name == name) {
+            Some(position) => position,
+            None => {
+                let path = if self.path.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{}/{name}", self.path)
+                };
+                self.groups.push(Self {
+                    name,
+                    path,
+                    ..Self::default()
+                });
+                self.groups.len() - 1
+            }
+        };
+        &mut self.groups[position]
+    }
+
+    /// The indices of the previews in the order they're shown, along with the path of the
+    /// group they're directly within, skipping the contents of groups that aren't expanded.
+    pub fn visible(&self, is_expanded: &dyn Fn(&str) -> bool) -> Vec<(usize, &str)> {
+        let mut previews = Vec::new();
+        self.collect_visible(is_expanded, &mut previews);
+        previews
+    }
+
+    fn collect_visible<'b>(
+        &'b self,
+        is_expanded: &dyn Fn(&str) -> bool,
+        previews: &mut Vec<(usize, &'b str)>,
+    ) {
+        for group in &self.groups {
+            if is_expanded(&group.path) {
+                group.collect_visible(is_expanded, previews);
+            }
+        }
+        previews.extend(
+            self.previews
+                .iter()
+                .map(|&(index, _)| (index, self.path.as_str())),
+        );
+    }
+
+    /// The number of previews within this group, including those in nested groups.
+    pub fn count(&self) -> usize {
+        self.previews.len() + self.groups.iter().map(Group::count).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn previews() -> Vec<Metadata> {
+        vec![
+            Metadata::new("TextField").group("Forms/Inputs"),
+            Metadata::new("Button"),
+            Metadata::new("Checkbox").group("Forms/Inputs"),
+            Metadata::new("Form").group("Forms"),
+            Metadata::new("Card").group("Layout"),
+        ]
+    }
+
+    /// Previews are nested within a group for each segment of their group path.
+    #[test]
+    fn tree_nests_groups() {
+        let previews = previews();
+        let root = Group::tree(previews.iter().enumerate());
+
+        let names: Vec<_> = root.groups.iter().map(|group| group.name).collect();
+        assert_eq!(names, ["Forms", "Layout"]);
+        assert_eq!(root.previews.len(), 1);
+
+        let inputs = &root.groups[0].groups[0];
+        assert_eq!(inputs.path, "Forms/Inputs");
+        assert_eq!(inputs.previews.len(), 2);
+    }
+
+    /// Counts include the previews of nested groups.
+    #[test]
+    fn count_includes_nested_groups() {
+        let previews = previews();
+        let root = Group::tree(previews.iter().enumerate());
+        assert_eq!(root.count(), 5);
+        assert_eq!(root.groups[0].count(), 3);
+    }
+
+    /// Visible previews are listed in the order they're shown, skipping collapsed groups.
+    #[test]
+    fn visible_skips_collapsed_groups() {
+        let previews = previews();
+        let root = Group::tree(previews.iter().enumerate());
+
+        assert_eq!(
+            root.visible(&|_| true),
+            [
+                (0, "Forms/Inputs"),
+                (2, "Forms/Inputs"),
+                (3, "Forms"),
+                (4, "Layout"),
+                (1, ""),
+            ]
+        );
+        assert_eq!(
+            root.visible(&|path| path != "Forms/Inputs"),
+            [(3, "Forms"), (4, "Layout"), (1, "")]
+        );
+    }
+}
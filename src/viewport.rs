@@ -0,0 +1,197 @@
+//! Viewport presets that constrain the size of the preview area.
+
+use iced::Size;
+
+use crate::test::size_input::SizeInput;
+
+/// A named size the preview can be constrained to, e.g. a phone or desktop screen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Viewport {
+    /// The display name of the viewport.
+    pub name: String,
+    /// The size of the viewport in logical pixels.
+    pub size: Size,
+}
+
+impl Viewport {
+    /// Creates a new [`Viewport`] with the given `name` and size.
+    pub fn new(name: impl Into<String>, width: f32, height: f32) -> Self {
+        Self {
+            name: name.into(),
+            size: Size::new(width, height),
+        }
+    }
+
+    /// The viewport presets that are available by default.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("Phone", 360.0, 640.0),
+            Self::new("Tablet", 768.0, 1024.0),
+            Self::new("Desktop", 1440.0, 900.0),
+        ]
+    }
+
+    /// A display label for the viewport, e.g. "Phone (360×640)".
+    pub fn label(&self) -> String {
+        format!("{} ({}×{})", self.name, self.size.width, self.size.height)
+    }
+}
+
+/// How the preview area is sized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Selection {
+    /// The preview fills the available space.
+    #[default]
+    Fill,
+    /// The preview is constrained to the preset at the given index.
+    Preset(usize),
+    /// The preview is constrained to the custom size entered by the user.
+    Custom,
+    /// The preview is rendered once for every preset side by side.
+    All,
+}
+
+/// Messages that change the viewport of the preview area.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Selects how the preview area is sized.
+    Select(Selection),
+    /// Changes the width of the custom viewport.
+    ChangeWidth(String),
+    /// Changes the height of the custom viewport.
+    ChangeHeight(String),
+}
+
+/// The viewport presets and the current selection.
+#[derive(Debug)]
+pub struct State {
+    /// The available viewport presets.
+    pub presets: Vec<Viewport>,
+    /// How the preview area is currently sized.
+    pub selection: Selection,
+    /// The width input for the custom viewport.
+    pub width_input: SizeInput,
+    /// The height input for the custom viewport.
+    pub height_input: SizeInput,
+    /// The last valid size of the custom viewport.
+    custom: Size,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            presets: Viewport::defaults(),
+            selection: Selection::default(),
+            width_input: SizeInput::new("1024"),
+            height_input: SizeInput::new("768"),
+            custom: Size::new(1024.0, 768.0),
+        }
+    }
+}
+
+impl State {
+    /// Replaces the viewport presets, resetting the selection if it no longer exists.
+    pub fn set_presets(&mut self, presets: Vec<Viewport>) {
+        self.presets = presets;
+        if let Selection::Preset(index) = self.selection
+            && index >= self.presets.len()
+        {
+            self.selection = Selection::Fill;
+        }
+    }
+
+    /// Updates the viewport state based on the given message.
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Select(selection) => self.selection = selection,
+            Message::ChangeWidth(width) => {
+                self.width_input.update(width);
+                if let Some(width) = self.width_input.value() {
+                    self.custom.width = width;
+                }
+            }
+            Message::ChangeHeight(height) => {
+                self.height_input.update(height);
+                if let Some(height) = self.height_input.value() {
+                    self.custom.height = height;
+                }
+            }
+        }
+    }
+
+    /// All selections the user can choose from.
+    pub fn selections(&self) -> Vec<Selection> {
+        std::iter::once(Selection::Fill)
+            .chain((0..self.presets.len()).map(Selection::Preset))
+            .chain([Selection::Custom, Selection::All])
+            .collect()
+    }
+
+    /// A display label for the given `selection`.
+    pub fn label(&self, selection: &Selection) -> String {
+        match selection {
+            Selection::Fill => "Fill".to_string(),
+            Selection::Preset(index) => self
+                .presets
+                .get(*index)
+                .map(Viewport::label)
+                .unwrap_or_default(),
+            Selection::Custom => "Custom".to_string(),
+            Selection::All => "All viewports".to_string(),
+        }
+    }
+
+    /// The viewports the preview is rendered in, which is empty if the preview fills the available space.
+    pub fn active(&self) -> Vec<Viewport> {
+        match self.selection {
+            Selection::Fill => Vec::new(),
+            Selection::Preset(index) => self.presets.get(index).cloned().into_iter().collect(),
+            Selection::Custom => vec![Viewport {
+                name: "Custom".to_string(),
+                size: self.custom,
+            }],
+            Selection::All => self.presets.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Filling the available space should not constrain the preview to any viewport.
+    #[test]
+    fn fill_has_no_active_viewports() {
+        let state = State::default();
+        assert!(state.active().is_empty());
+    }
+
+    /// Showing all viewports should render the preview once per preset.
+    #[test]
+    fn all_renders_every_preset() {
+        let mut state = State::default();
+        state.update(Message::Select(Selection::All));
+        assert_eq!(state.active(), state.presets);
+    }
+
+    /// Invalid custom sizes should keep the last valid size.
+    #[test]
+    fn custom_keeps_last_valid_size() {
+        let mut state = State::default();
+        state.update(Message::Select(Selection::Custom));
+        state.update(Message::ChangeWidth("400".to_string()));
+        state.update(Message::ChangeHeight("abc".to_string()));
+
+        assert_eq!(state.active()[0].size, Size::new(400.0, 768.0));
+        assert!(!state.height_input.is_valid());
+    }
+
+    /// Replacing presets should reset a selection that no longer exists.
+    #[test]
+    fn set_presets_resets_missing_selection() {
+        let mut state = State::default();
+        state.update(Message::Select(Selection::Preset(2)));
+        state.set_presets(vec![Viewport::new("Watch", 200.0, 200.0)]);
+        assert_eq!(state.selection, Selection::Fill);
+    }
+}
@@ -2,20 +2,39 @@ pub mod badge;
 pub mod config_pane;
 pub mod dialog;
 pub mod recorder;
+pub mod scrub;
 pub mod split;
+pub mod stage;
 
 pub use badge::*;
 pub use config_pane::*;
 pub use dialog::dialog;
 pub use recorder::recorder;
+pub use scrub::scrub;
+pub use stage::stage;
 
 use iced::theme;
-use iced::widget::{Column, button, container, pick_list, row, space, svg, text, text_input};
-use iced::{Alignment::Center, Element, Length::Fill, Theme, border};
+use iced::widget::{
+    Column, Row, button, checkbox, column, container, pick_list, rich_text, row, scrollable, space,
+    span, svg, text, text_input, themer,
+};
+use iced::{Alignment::Center, Element, Font, Length::Fill, Theme, border, font, padding};
 use iced_anim::Animated;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::path::PathBuf;
 
-use crate::preview::Descriptor;
-use crate::{message::Message, preview::Preview, test};
+use crate::Metadata;
+use crate::canvas::{self, Background, Zoom};
+use crate::compare;
+use crate::keybinding::{Action, Keybinding};
+use crate::metadata::Status;
+use crate::palette;
+use crate::preview::{Descriptor, ReplayError};
+use crate::search::{self, Query};
+use crate::tree::Group;
+use crate::viewport::{self, Viewport};
+use crate::{message::Message, test};
 
 /// The ID of the search input field.
 pub const SEARCH_INPUT_ID: &str = "search_input";
@@ -32,12 +51,22 @@ pub fn theme_picker<'a>(theme: Option<Theme>) -> Element<'a, Message> {
 }
 
 /// The header shown above the preview area.
-pub fn header<'a>(theme: &'a Option<Animated<Theme>>) -> Element<'a, Message> {
+pub fn header<'a>(
+    theme: &'a Option<Animated<Theme>>,
+    viewport: &'a viewport::State,
+    is_matrix: bool,
+    is_comparing: bool,
+) -> Element<'a, Message> {
     row![
         reset_button(),
+        pop_out_button(),
         space::horizontal(),
+        viewport_picker(viewport),
+        compare_toggle(is_comparing),
+        matrix_toggle(is_matrix),
         theme_picker(theme.as_ref().map(|t| t.target().clone())),
     ]
+    .spacing(8)
     .align_y(Center)
     .padding(10)
     .into()
@@ -67,20 +96,33 @@ pub fn search_input(search: &str) -> Element<'_, Message> {
 
 /// A button to reset the current preview.
 pub fn reset_button<'a>() -> Element<'a, Message> {
+    header_button(crate::icon::refresh(), "Reset", Message::ResetPreview)
+}
+
+/// A button to open the current preview in its own window.
+pub fn pop_out_button<'a>() -> Element<'a, Message> {
+    header_button(crate::icon::external_link(), "Pop out", Message::PopOut)
+}
+
+/// A button in the header with an `icon` next to its `label`.
+fn header_button<'a>(
+    icon: svg::Svg<'a>,
+    label: &'a str,
+    on_press: Message,
+) -> Element<'a, Message> {
     button(
         row![
-            crate::icon::refresh()
-                .width(16)
+            icon.width(16)
                 .height(16)
                 .style(|theme, _status| svg::Style {
                     color: Some(theme.seed().text),
                 }),
-            text("Reset").size(14),
+            text(label).size(14),
         ]
         .spacing(6)
         .align_y(Center),
     )
-    .on_press(Message::ResetPreview)
+    .on_press(on_press)
     .style(|theme: &Theme, status| {
         let pair = match status {
             button::Status::Hovered => theme.palette().background.weaker,
@@ -98,6 +140,173 @@ pub fn reset_button<'a>() -> Element<'a, Message> {
     .into()
 }
 
+/// The viewport dropdown shown in the header, followed by size inputs for a custom viewport.
+pub fn viewport_picker(state: &viewport::State) -> Element<'_, Message> {
+    let picker = pick_list(Some(state.selection), state.selections(), |selection| {
+        state.label(selection)
+    })
+    .text_size(14)
+    .on_select(|selection| Message::Viewport(viewport::Message::Select(selection)))
+    .style(crate::style::pick_list::default)
+    .menu_style(crate::style::pick_list::menu);
+
+    if state.selection != viewport::Selection::Custom {
+        return picker.into();
+    }
+
+    row![
+        picker,
+        text_input("W", state.width_input.display())
+            .style(|theme, status| crate::style::text_input::validated(
+                theme,
+                status,
+                state.width_input.is_valid()
+            ))
+            .size(14)
+            .width(60)
+            .on_input(|w| Message::Viewport(viewport::Message::ChangeWidth(w))),
+        text("×").size(14),
+        text_input("H", state.height_input.display())
+            .style(|theme, status| crate::style::text_input::validated(
+                theme,
+                status,
+                state.height_input.is_valid()
+            ))
+            .size(14)
+            .width(60)
+            .on_input(|h| Message::Viewport(viewport::Message::ChangeHeight(h))),
+    ]
+    .spacing(6)
+    .align_y(Center)
+    .into()
+}
+
+/// A button that toggles the theme matrix in the preview area.
+pub fn matrix_toggle<'a>(is_matrix: bool) -> Element<'a, Message> {
+    button(text("Theme matrix").size(14))
+        .on_press(Message::ToggleMatrix)
+        .style(move |theme, status| crate::style::button::toggle(theme, status, is_matrix))
+        .into()
+}
+
+/// A button that toggles compare mode in the preview area.
+pub fn compare_toggle<'a>(is_comparing: bool) -> Element<'a, Message> {
+    button(text("Compare").size(14))
+        .on_press(Message::Compare(compare::Message::Toggle))
+        .style(move |theme, status| crate::style::button::toggle(theme, status, is_comparing))
+        .into()
+}
+
+/// The toolbar above the two sides of compare mode, with the preview shown on the right,
+/// whether interactions are synced, and the side that the configuration pane controls.
+pub fn compare_toolbar<'a>(
+    state: &'a compare::State,
+    targets: Vec<compare::Target>,
+) -> Element<'a, Message> {
+    let side_button = |label: &'static str, side: compare::Side| {
+        button(text(label).size(14))
+            .on_press(Message::Compare(compare::Message::SelectSide(side)))
+            .style(move |theme, status| {
+                crate::style::button::toggle(theme, status, state.side == side)
+            })
+    };
+
+    row![
+        text("Compare with").size(14),
+        pick_list(
+            Some(state.target.clone()),
+            targets,
+            compare::Target::to_string
+        )
+        .text_size(14)
+        .on_select(|target| Message::Compare(compare::Message::SelectTarget(target)))
+        .style(crate::style::pick_list::default)
+        .menu_style(crate::style::pick_list::menu),
+        checkbox(state.is_synced)
+            .label("Sync interactions")
+            .text_size(14)
+            .on_toggle(|is_synced| Message::Compare(compare::Message::ToggleSync(is_synced))),
+        space::horizontal(),
+        text("Configure").size(14).style(crate::style::text::muted),
+        row![
+            side_button("Left", compare::Side::Left),
+            side_button("Right", compare::Side::Right),
+        ]
+        .spacing(4),
+    ]
+    .spacing(8)
+    .align_y(Center)
+    .padding([6, 10])
+    .into()
+}
+
+/// A banner listing the previews whose messages couldn't be replayed after a hot reload,
+/// or `None` if every preview was restored.
+pub fn replay_failures<'a>(failures: &'a [(String, ReplayError)]) -> Option<Element<'a, Message>> {
+    if failures.is_empty() {
+        return None;
+    }
+
+    Some(
+        container(
+            column![
+                row![
+                    text("Some previews couldn't be restored after the hot reload").size(14),
+                    space::horizontal(),
+                    button(text("Dismiss").size(14))
+                        .on_press(Message::DismissReplayFailures)
+                        .style(button::text),
+                ]
+                .align_y(Center),
+                Column::with_children(
+                    failures
+                        .iter()
+                        .map(|(label, error)| text!("{label}: {error}").size(13).into()),
+                ),
+            ]
+            .spacing(4),
+        )
+        .padding([8, 10])
+        .width(Fill)
+        .style(crate::style::container::warning)
+        .into(),
+    )
+}
+
+/// A banner with the outcome of the last screenshot taken from the app,
+/// or `None` if there's nothing to show.
+pub fn screenshot_result<'a>(
+    result: Option<&'a Result<PathBuf, String>>,
+) -> Option<Element<'a, Message>> {
+    let (message, style): (String, fn(&Theme) -> container::Style) = match result? {
+        Ok(path) => (
+            format!("Screenshot saved to {}", path.display()),
+            crate::style::container::success,
+        ),
+        Err(error) => (
+            format!("Failed to capture screenshot: {error}"),
+            crate::style::container::warning,
+        ),
+    };
+
+    Some(
+        container(
+            row![
+                text(message).size(14),
+                space::horizontal(),
+                button(text("Dismiss").size(14))
+                    .on_press(Message::DismissScreenshotResult)
+                    .style(button::text),
+            ]
+            .align_y(Center),
+        )
+        .padding([8, 10])
+        .width(Fill)
+        .style(style)
+        .into(),
+    )
+}
+
 /// A button to stop recording and save the test.
 pub fn stop_recording_button<'a>() -> Element<'a, Message> {
     #[inline]
@@ -130,68 +339,419 @@ pub fn stop_recording_button<'a>() -> Element<'a, Message> {
     .into()
 }
 
-/// The main preview area showing the selected `preview`.
-pub fn preview_area(preview: Option<&dyn Preview>) -> Element<'_, Message> {
-    container(if let Some(preview) = preview {
-        preview.view()
-    } else {
-        text("No preview selected").into()
-    })
-    .center(Fill)
+/// The main preview area showing the decorated view of the selected preview
+/// on a canvas that can be zoomed and panned.
+///
+/// The scale the preview is fitted with is reported whenever it differs from the `fit_scale`.
+pub fn preview_area(
+    content: Option<Element<'_, Message>>,
+    settings: canvas::Settings,
+    fit_scale: Option<f32>,
+) -> Element<'_, Message> {
+    match content {
+        Some(content) => stage(content, settings)
+            .on_pan(|offset| Message::Canvas(canvas::Message::Pan(offset)))
+            .on_fit(fit_scale, |scale| {
+                Message::Canvas(canvas::Message::Fitted(scale))
+            })
+            .into(),
+        None => container(text("No preview selected")).center(Fill).into(),
+    }
+}
+
+/// The toolbar above the preview area with the zoom and background of the canvas.
+pub fn canvas_toolbar<'a>(
+    settings: canvas::Settings,
+    state: &'a canvas::State,
+) -> Element<'a, Message> {
+    let zoom_button = |label: &'static str, message: canvas::Message| {
+        button(text(label).size(14))
+            .on_press(Message::Canvas(message))
+            .style(crate::style::button::subtle)
+    };
+
+    let backgrounds = vec![
+        Background::Theme,
+        Background::Checkerboard,
+        Background::Custom(state.custom_color(&settings)),
+    ];
+
+    let color_input: Option<Element<'a, Message>> =
+        matches!(settings.background, Background::Custom(_)).then(|| {
+            text_input("#rrggbb", &state.color_input)
+                .style(|theme, status| {
+                    crate::style::text_input::validated(
+                        theme,
+                        status,
+                        state.color_input.is_empty() || state.is_color_valid(),
+                    )
+                })
+                .size(14)
+                .width(90)
+                .on_input(|input| Message::Canvas(canvas::Message::ChangeColor(input)))
+                .into()
+        });
+
+    row![
+        zoom_button("−", canvas::Message::ZoomOut),
+        pick_list(Some(settings.zoom), &Zoom::LEVELS[..], Zoom::to_string)
+            .text_size(14)
+            .on_select(|zoom| Message::Canvas(canvas::Message::Zoom(zoom)))
+            .style(crate::style::pick_list::default)
+            .menu_style(crate::style::pick_list::menu),
+        zoom_button("+", canvas::Message::ZoomIn),
+        button(text("Center").size(14))
+            .on_press_maybe(
+                (settings.offset != iced::Vector::ZERO)
+                    .then_some(Message::Canvas(canvas::Message::ResetPan))
+            )
+            .style(crate::style::button::ghost_subtle),
+        space::horizontal(),
+        pick_list(
+            Some(settings.background),
+            backgrounds,
+            Background::to_string
+        )
+        .text_size(14)
+        .on_select(|background| Message::Canvas(canvas::Message::Background(background)))
+        .style(crate::style::pick_list::default)
+        .menu_style(crate::style::pick_list::menu),
+        color_input,
+    ]
+    .spacing(6)
+    .align_y(Center)
+    .padding([6, 10])
+    .into()
+}
+
+/// The size of a single cell in the theme matrix.
+const MATRIX_CELL_SIZE: (f32, f32) = (320.0, 240.0);
+
+/// The preview area rendering the preview returned by `view` once for each of the `themes`.
+///
+/// Every cell renders the same preview, so interacting with any cell updates all of them.
+pub fn preview_matrix<'a>(
+    themes: &'a [Theme],
+    view: impl Fn() -> Element<'a, Message>,
+) -> Element<'a, Message> {
+    let (width, height) = MATRIX_CELL_SIZE;
+    let cells = themes.iter().map(|theme| -> Element<'a, Message> {
+        let cell = container(view())
+            .center_x(width)
+            .center_y(height)
+            .clip(true)
+            .style(crate::style::container::canvas);
+
+        column![
+            text(theme.to_string())
+                .size(12)
+                .style(crate::style::text::muted),
+            themer(Some(theme.clone()), cell),
+        ]
+        .spacing(4)
+        .into()
+    });
+
+    scrollable(
+        container(Row::with_children(cells).spacing(16).wrap())
+            .padding(16)
+            .width(Fill),
+    )
+    .height(Fill)
     .into()
 }
 
-/// A list of available previews the user can select from to view.
+/// The preview area rendering the preview returned by `view` once for each of the
+/// `viewports`, constrained to the viewport's size inside a scrollable canvas.
+pub fn preview_viewports<'a>(
+    viewports: Vec<Viewport>,
+    view: impl Fn() -> Element<'a, Message>,
+) -> Element<'a, Message> {
+    let frames = viewports
+        .into_iter()
+        .map(|viewport| -> Element<'a, Message> {
+            column![
+                text(viewport.label())
+                    .size(12)
+                    .style(crate::style::text::muted),
+                container(view())
+                    .width(viewport.size.width)
+                    .height(viewport.size.height)
+                    .clip(true)
+                    .style(crate::style::container::canvas),
+            ]
+            .spacing(4)
+            .into()
+        });
+
+    scrollable(container(Row::with_children(frames).spacing(16)).padding(16))
+        .direction(scrollable::Direction::Both {
+            vertical: scrollable::Scrollbar::default(),
+            horizontal: scrollable::Scrollbar::default(),
+        })
+        .width(Fill)
+        .height(Fill)
+        .into()
+}
+
+/// How far each level of the preview tree is indented, which lines up nested items
+/// with the name of their group after its chevron.
+const TREE_INDENT: f32 = 18.0;
+
+/// A list of available previews the user can select from to view,
+/// nested in a collapsible tree by their group path.
+///
+/// Groups in `collapsed` hide their previews unless there is a search `query`,
+/// which expands every group that contains a matching preview and highlights
+/// the characters of each preview that match the query.
 pub fn preview_list<'a>(
     previews: impl IntoIterator<Item = (usize, &'a Descriptor)>,
     selected_index: Option<usize>,
+    collapsed: &HashSet<String>,
+    query: &Query,
 ) -> Element<'a, Message> {
-    let previews: Vec<(usize, &Descriptor)> = previews.into_iter().collect();
-    if previews.is_empty() {
+    let root = Group::tree(
+        previews
+            .into_iter()
+            .map(|(index, descriptor)| (index, descriptor.metadata())),
+    );
+
+    if root.count() == 0 {
         text("No previews available").size(14).into()
     } else {
-        previews
-            .iter()
-            .fold(Column::new(), |column, (index, descriptor)| {
-                let is_selected = Some(*index) == selected_index;
-                column.push(preview_list_item(descriptor, *index, is_selected))
-            })
-            .into()
+        preview_group_items(&root, 0, selected_index, query, &|path| {
+            !query.is_empty() || !collapsed.contains(path)
+        })
     }
 }
 
-/// A single preview that is shown in the list of available previews.
-fn preview_list_item(
-    descriptor: &Descriptor,
+/// The nested groups followed by the previews of a `group`, indented by their `depth`.
+fn preview_group_items<'a>(
+    group: &Group<'a>,
+    depth: u16,
+    selected_index: Option<usize>,
+    query: &Query,
+    is_expanded: &dyn Fn(&str) -> bool,
+) -> Element<'a, Message> {
+    let groups = group.groups.iter().map(|group| {
+        let expanded = is_expanded(group.path.as_str());
+        let header = preview_group_header(group, depth, expanded);
+        if expanded {
+            column![
+                header,
+                preview_group_items(group, depth + 1, selected_index, query, is_expanded),
+            ]
+            .into()
+        } else {
+            header
+        }
+    });
+
+    // Variants are only nested while they follow their parent, which search results may not.
+    let previews = group
+        .previews
+        .iter()
+        .enumerate()
+        .map(|(position, &(index, metadata))| {
+            let is_nested = metadata.variant.is_some()
+                && position
+                    .checked_sub(1)
+                    .is_some_and(|previous| group.previews[previous].1.label == metadata.label);
+            let is_selected = Some(index) == selected_index;
+            preview_list_item(metadata, index, depth, is_nested, is_selected, query)
+        });
+
+    Column::with_children(groups.chain(previews)).into()
+}
+
+/// The header of a group in the list of previews that expands or collapses it when pressed.
+fn preview_group_header<'a>(
+    group: &Group<'a>,
+    depth: u16,
+    is_expanded: bool,
+) -> Element<'a, Message> {
+    let chevron = if is_expanded {
+        crate::icon::chevron_down()
+    } else {
+        crate::icon::chevron_right()
+    };
+
+    button(
+        row![
+            chevron.width(14).height(14).style(crate::style::svg::text),
+            text(group.name).size(14),
+            space::horizontal(),
+            round_badge(group.count().to_string(), false),
+        ]
+        .spacing(4)
+        .align_y(Center)
+        .padding(padding::left(f32::from(depth) * TREE_INDENT)),
+    )
+    .width(Fill)
+    .on_press(Message::ToggleGroup(group.path.clone()))
+    .style(|theme, status| preview_list_item_style(theme, status, false))
+    .into()
+}
+
+/// A single preview that is shown in the list of available previews,
+/// with the characters that match the search `query` in bold.
+///
+/// Variants that are `nested` underneath their parent preview only show the variant name.
+fn preview_list_item<'a>(
+    metadata: &'a Metadata,
     index: usize,
+    depth: u16,
+    is_nested: bool,
     is_selected: bool,
-) -> Element<'_, Message> {
-    button(text(&descriptor.metadata().label).size(14))
-        .width(Fill)
-        .on_press(Message::SelectPreview(index))
-        .style(move |theme, status| {
-            let base = button::primary(theme, status);
-            if is_selected {
-                button::Style {
-                    background: Some(theme.palette().primary.base.color.into()),
-                    text_color: theme.palette().primary.base.text,
-                    border: border::rounded(4),
-                    ..base
-                }
+    query: &Query,
+) -> Element<'a, Message> {
+    let (label, indent) = match &metadata.variant {
+        Some(variant) if is_nested => (Cow::Borrowed(variant.as_str()), 12.0),
+        _ => (metadata.qualified_label(), 0.0),
+    };
+    let indent = f32::from(depth) * TREE_INDENT + indent;
+
+    let label = highlighted_text(&label, &query.highlights(&label));
+
+    // Stable components are the norm, so only the other statuses stand out in the list.
+    let status = metadata
+        .status
+        .as_ref()
+        .filter(|status| **status != Status::Stable)
+        .map(|status| status_badge(status, 10));
+
+    button(
+        row![label, space::horizontal(), status]
+            .spacing(4)
+            .align_y(Center)
+            .padding(padding::left(indent)),
+    )
+    .width(Fill)
+    .on_press(Message::SelectPreview(index))
+    .style(move |theme, status| preview_list_item_style(theme, status, is_selected))
+    .into()
+}
+
+/// Text where the characters at the `highlights` positions are shown in a bold font.
+fn highlighted_text<'a>(content: &str, highlights: &[usize]) -> Element<'a, Message> {
+    if highlights.is_empty() {
+        return text(content.to_owned()).size(14).into();
+    }
+
+    let bold = Font {
+        weight: font::Weight::Bold,
+        ..Font::default()
+    };
+    let spans: Vec<text::Span<'a>> = search::segments(content, highlights)
+        .into_iter()
+        .map(|(segment, is_highlighted)| {
+            let span = span(segment.to_owned());
+            if is_highlighted {
+                span.font(bold)
             } else {
-                let default = button::text(theme, status);
-                let pair: Option<theme::palette::Pair> = match status {
-                    button::Status::Hovered => Some(theme.palette().background.strong),
-                    button::Status::Pressed => Some(theme.palette().background.stronger),
-                    _ => None,
-                };
-                button::Style {
-                    background: pair.map(|p| p.color.into()),
-                    text_color: pair.map(|p| p.text).unwrap_or(default.text_color),
-                    border: border::rounded(4),
-                    ..default
-                }
+                span
             }
         })
-        .into()
+        .collect();
+    rich_text(spans).size(14).into()
+}
+
+/// The style of an item in the list of previews, which is highlighted while selected.
+fn preview_list_item_style(
+    theme: &Theme,
+    status: button::Status,
+    is_selected: bool,
+) -> button::Style {
+    let base = button::primary(theme, status);
+    if is_selected {
+        button::Style {
+            background: Some(theme.palette().primary.base.color.into()),
+            text_color: theme.palette().primary.base.text,
+            border: border::rounded(4),
+            ..base
+        }
+    } else {
+        let default = button::text(theme, status);
+        let pair: Option<theme::palette::Pair> = match status {
+            button::Status::Hovered => Some(theme.palette().background.strong),
+            button::Status::Pressed => Some(theme.palette().background.stronger),
+            _ => None,
+        };
+        button::Style {
+            background: pair.map(|p| p.color.into()),
+            text_color: pair.map(|p| p.text).unwrap_or(default.text_color),
+            border: border::rounded(4),
+            ..default
+        }
+    }
+}
+
+/// The content of the command palette, with an input for the `state`'s query
+/// above the `matches` for it.
+pub fn command_palette<'a>(
+    state: &'a palette::State,
+    matches: Vec<palette::Match>,
+) -> Element<'a, Message> {
+    let input = text_input("Search previews, tabs, themes, and actions", &state.query)
+        .id(palette::INPUT_ID)
+        .on_input(|query| Message::Palette(palette::Message::ChangeQuery(query)))
+        .on_submit(Message::Palette(palette::Message::Submit));
+
+    let list: Element<'a, Message> = if matches.is_empty() {
+        text("No matching commands")
+            .size(14)
+            .style(crate::style::text::muted)
+            .into()
+    } else {
+        let items = matches.into_iter().enumerate().map(|(index, m)| {
+            let is_selected = index == state.selected;
+            button(
+                row![
+                    highlighted_text(&m.command.label, &m.highlights),
+                    space::horizontal(),
+                    text(m.command.category)
+                        .size(12)
+                        .style(crate::style::text::muted),
+                ]
+                .spacing(8)
+                .align_y(Center),
+            )
+            .width(Fill)
+            .on_press(Message::Palette(palette::Message::Run(index)))
+            .style(move |theme, status| preview_list_item_style(theme, status, is_selected))
+            .into()
+        });
+        scrollable(Column::with_children(items).spacing(2))
+            .height(320)
+            .into()
+    };
+
+    column![input, list].spacing(10).into()
+}
+
+/// The content of the help overlay, which lists the shortcuts bound to each action.
+pub fn keybindings_help(keybindings: &[Keybinding]) -> Element<'_, Message> {
+    let rows = Action::ALL.into_iter().filter_map(|action| {
+        let shortcuts: Vec<_> = keybindings
+            .iter()
+            .filter(|binding| binding.action == action)
+            .map(|binding| badge(binding.shortcut.to_string()))
+            .collect();
+        if shortcuts.is_empty() {
+            return None;
+        }
+
+        Some(
+            row![
+                text(action.description()).size(14),
+                space::horizontal(),
+                Row::with_children(shortcuts).spacing(4),
+            ]
+            .spacing(8)
+            .align_y(Center)
+            .into(),
+        )
+    });
+
+    Column::with_children(rows).spacing(8).into()
 }
@@ -4,6 +4,7 @@ use iced::{
 };
 
 use crate::app::Message;
+use crate::metadata::Status;
 
 /// A small badge that shows some `content` within it.
 pub fn badge<'a>(content: impl IntoFragment<'a>) -> Element<'a, Message> {
@@ -52,3 +53,25 @@ pub fn mini_badge<'a>(content: impl IntoFragment<'a>) -> Element<'a, Message> {
         })
         .into()
 }
+
+/// A badge showing the lifecycle `status` of a component, colored by how safe it is to use.
+pub fn status_badge<'a>(status: &Status, size: u16) -> Element<'a, Message> {
+    let status = status.clone();
+    container(text(status.name()).size(size))
+        .padding([1, 6])
+        .style(move |theme: &Theme| {
+            let palette = theme.palette();
+            let pair = match status {
+                Status::Experimental => palette.warning.weak,
+                Status::Stable => palette.success.weak,
+                Status::Deprecated(_) => palette.danger.weak,
+            };
+            container::Style {
+                background: Some(pair.color.into()),
+                text_color: Some(pair.text),
+                border: border::rounded(4),
+                ..container::Style::default()
+            }
+        })
+        .into()
+}
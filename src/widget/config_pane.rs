@@ -1,4 +1,5 @@
 pub mod about_pane;
+pub mod code_pane;
 pub mod message_pane;
 pub mod parameter_pane;
 pub mod performance_pane;
@@ -28,8 +29,20 @@ pub fn config_pane<'a>(
     responsive(move |size| {
         // The main content of the config pane
         let content = match tab {
-            ConfigTab::About => about_pane::about_pane(descriptor.metadata()),
-            ConfigTab::Parameters => parameter_pane::parameter_pane(descriptor.preview.params()),
+            ConfigTab::About => about_pane::about_pane(
+                descriptor.metadata(),
+                &descriptor.description,
+                &app.current_theme(),
+            ),
+            ConfigTab::Code => code_pane::code_pane(
+                app.snippet()
+                    .map(|snippet| snippet.items.as_slice())
+                    .unwrap_or_default(),
+                &app.current_theme(),
+            ),
+            ConfigTab::Parameters => {
+                parameter_pane::parameter_pane(descriptor.preview.params(), app.number_draft())
+            }
             ConfigTab::Messages => {
                 message_pane::message_pane(descriptor.preview.visible_messages())
             }
@@ -44,6 +57,7 @@ pub fn config_pane<'a>(
         // Trailing element shown on the right of the config tabs
         let trailing = match tab {
             ConfigTab::About
+            | ConfigTab::Code
             | ConfigTab::Parameters
             | ConfigTab::Performance
             | ConfigTab::Tests => None,
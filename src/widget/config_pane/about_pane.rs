@@ -1,19 +1,47 @@
 use iced::Alignment::Center;
-use iced::Element;
 use iced::Length::Fill;
 use iced::Length::Shrink;
-use iced::widget::{column, row, scrollable, space, text};
+use iced::widget::{Column, button, column, markdown, row, scrollable, space, text};
+use iced::{Element, Theme};
 
 use crate::style;
-use crate::widget::badge;
+use crate::widget::{badge, status_badge};
 use crate::{app::Message, metadata::Metadata};
 
 /// A pane shown in the configuration area displaying metadata about the preview.
-pub fn about_pane(metadata: &Metadata) -> Element<'_, Message> {
+///
+/// The `description` is rendered as Markdown, and clicking its links opens them in the browser.
+pub fn about_pane<'a>(
+    metadata: &'a Metadata,
+    description: &'a [markdown::Item],
+    theme: &Theme,
+) -> Element<'a, Message> {
+    let description: Element<'a, Message> = if description.is_empty() {
+        text("No description available.")
+            .style(style::text::muted)
+            .into()
+    } else {
+        markdown::view(description, theme).map(|url| Message::OpenUrl(url.to_string()))
+    };
+
+    let replacement: Option<Element<'a, Message>> = metadata
+        .status
+        .as_ref()
+        .and_then(|status| status.replacement())
+        .map(|replacement| {
+            text!("Deprecated: {replacement}")
+                .style(style::text::danger)
+                .into()
+        });
+
     scrollable(
         column![
             row![
                 text(&metadata.label).size(18),
+                metadata
+                    .status
+                    .as_ref()
+                    .map(|status| status_badge(status, 12)),
                 space::horizontal().width(Shrink),
                 row(metadata.tags.iter().cloned().map(badge))
                     .spacing(4)
@@ -22,15 +50,58 @@ pub fn about_pane(metadata: &Metadata) -> Element<'_, Message> {
             .spacing(8)
             .align_y(Center)
             .wrap(),
+            replacement,
             space::vertical().height(5),
-            if let Some(description) = &metadata.description {
-                text(description)
-            } else {
-                text("No description available.")
-            }
-            .style(style::text::muted),
+            description,
+            details(metadata),
         ]
+        .spacing(4)
         .width(Fill),
     )
     .into()
 }
+
+/// The owners, links, and custom fields of the `metadata`, each on their own row.
+fn details<'a>(metadata: &'a Metadata) -> Option<Element<'a, Message>> {
+    let detail = |name: &'a str, value: Element<'a, Message>| -> Element<'a, Message> {
+        row![
+            text(name).size(14).width(100).style(style::text::muted),
+            value
+        ]
+        .spacing(8)
+        .align_y(Center)
+        .into()
+    };
+
+    let owners = (!metadata.owners.is_empty())
+        .then(|| detail("Owners", text(metadata.owners.join(", ")).size(14).into()));
+
+    let links = (!metadata.links.is_empty()).then(|| {
+        detail(
+            "Links",
+            row(metadata.links.iter().map(|link| {
+                button(text(&link.label).size(14))
+                    .padding(0)
+                    .on_press(Message::OpenUrl(link.url.clone()))
+                    .style(button::text)
+                    .into()
+            }))
+            .spacing(12)
+            .wrap()
+            .into(),
+        )
+    });
+
+    let fields = metadata
+        .fields
+        .iter()
+        .map(|(key, value)| detail(key.as_str(), text(value).size(14).into()));
+
+    let rows: Vec<Element<'a, Message>> = owners.into_iter().chain(links).chain(fields).collect();
+    (!rows.is_empty()).then(|| {
+        Column::with_children(rows)
+            .spacing(4)
+            .padding(iced::padding::top(8))
+            .into()
+    })
+}
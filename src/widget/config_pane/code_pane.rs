@@ -0,0 +1,35 @@
+use iced::Length::Fill;
+use iced::widget::{button, column, markdown, row, scrollable, space, text};
+use iced::{Element, Theme};
+
+use crate::app::Message;
+use crate::style;
+
+/// A pane shown in the configuration area displaying the source code of the preview.
+///
+/// The `code` is a highlighted Markdown code block, and is empty if the source isn't available.
+pub fn code_pane<'a>(code: &'a [markdown::Item], theme: &Theme) -> Element<'a, Message> {
+    if code.is_empty() {
+        return text(
+            "Source code isn't available. Previews show their source when the app \
+            runs from within their workspace.",
+        )
+        .style(style::text::muted)
+        .into();
+    }
+
+    column![
+        row![
+            space::horizontal(),
+            button(text("Copy").size(14))
+                .padding([2, 8])
+                .on_press(Message::CopyCode)
+                .style(button::secondary),
+        ],
+        scrollable(markdown::view(code, theme).map(|url| Message::OpenUrl(url.to_string())))
+            .width(Fill)
+            .height(Fill),
+    ]
+    .spacing(4)
+    .into()
+}
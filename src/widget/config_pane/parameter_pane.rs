@@ -1,21 +1,35 @@
 use iced::Alignment::Center;
 use iced::Length::{FillPortion, Fit, Shrink};
+use iced::font::Family;
 use iced::widget::{
-    button, column, container, pick_list, responsive, right, row, scrollable, slider, space, svg,
-    table, text, text_input,
+    Button, Row, Svg, button, checkbox, column, container, pick_list, responsive, right, row,
+    scrollable, slider, space, svg, table, text, text_input, tooltip,
 };
-use iced::{Color, Element, Length, Theme, border};
+use iced::{Color, Element, Font, Length, Theme, border};
 
 use crate::style;
 use crate::{
     app::Message,
-    dynamic::{Param, Value},
+    dynamic::{Float, Param, Value, appearance},
 };
 
+/// The text entered into a number field that isn't a valid number yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberDraft {
+    /// The index of the parameter, followed by the positions of any parameters nested in it.
+    pub path: Vec<usize>,
+    /// The entered text.
+    pub input: String,
+}
+
 /// The pane containing the list of adjustable dynamic parameters for the preview.
 ///
 /// Dynamic parameters allow the user to modify certain parts of the preview at runtime.
-pub fn parameter_pane(params: &[Param]) -> Element<'_, Message> {
+/// The `draft` is shown instead of the number of the field it was entered into.
+pub fn parameter_pane<'a>(
+    params: &'a [Param],
+    draft: Option<&'a NumberDraft>,
+) -> Element<'a, Message> {
     if params.is_empty() {
         text("This preview has no adjustable parameters.")
             .size(16)
@@ -23,9 +37,9 @@ pub fn parameter_pane(params: &[Param]) -> Element<'_, Message> {
     } else {
         scrollable(responsive(|size| {
             if size.width < 576.0 {
-                vertical_view(params)
+                vertical_view(params, draft)
             } else {
-                table_view(params)
+                table_view(params, draft)
             }
         }))
         .spacing(4)
@@ -34,7 +48,7 @@ pub fn parameter_pane(params: &[Param]) -> Element<'_, Message> {
 }
 
 /// Displays the parameters in a table layout, typically for larger widths.
-pub fn table_view(params: &[Param]) -> Element<'_, Message> {
+pub fn table_view<'a>(params: &'a [Param], draft: Option<&'a NumberDraft>) -> Element<'a, Message> {
     let header_style = |theme: &Theme| text::Style {
         color: Some(theme.seed().text.scale_alpha(0.75)),
     };
@@ -42,7 +56,7 @@ pub fn table_view(params: &[Param]) -> Element<'_, Message> {
     let columns = [
         table::column(
             text("Name").size(14).style(header_style),
-            |(_, param): (usize, &Param)| text(&param.name).size(14),
+            |(_, param): (usize, &Param)| name(param),
         )
         .width(FillPortion(1)),
         table::column(
@@ -51,7 +65,7 @@ pub fn table_view(params: &[Param]) -> Element<'_, Message> {
                 space::horizontal(),
                 undo_button(),
             ],
-            |(index, param): (usize, &Param)| field(param, index),
+            move |(index, param): (usize, &'a Param)| field(param, index, vec![index], draft),
         )
         .width(FillPortion(3)),
     ];
@@ -86,11 +100,14 @@ pub fn undo_button<'a>() -> Element<'a, Message> {
 }
 
 /// Displays the parameters in a vertical layout, typically for narrow widths.
-pub fn vertical_view(params: &[Param]) -> Element<'_, Message> {
+pub fn vertical_view<'a>(
+    params: &'a [Param],
+    draft: Option<&'a NumberDraft>,
+) -> Element<'a, Message> {
     let fields = params
         .iter()
         .enumerate()
-        .map(|(index, param)| labeled(&param.name, field(param, index)));
+        .map(|(index, param)| labeled(name(param), field(param, index, vec![index], draft)));
 
     // Place the undo button near the top so vertical layouts can reset params.
     column![right(undo_button()), column(fields).spacing(10)]
@@ -98,8 +115,30 @@ pub fn vertical_view(params: &[Param]) -> Element<'_, Message> {
         .into()
 }
 
-/// Displays an editable field for a dynamic `param`.
-pub fn field(param: &Param, index: usize) -> Element<'_, Message> {
+/// Displays the name of a `param`, with its description as a tooltip if it has one.
+fn name(param: &Param) -> Element<'_, Message> {
+    let name = text(&param.name).size(14);
+    match &param.description {
+        Some(description) => tooltip(
+            name,
+            container(text(description).size(14))
+                .padding(6)
+                .width(Fit.max(320)),
+            tooltip::Position::Top,
+        )
+        .style(crate::style::container::tooltip_background)
+        .into(),
+        None => name.into(),
+    }
+}
+
+/// Displays an editable field for a dynamic `param` at the given `path`.
+pub fn field<'a>(
+    param: &'a Param,
+    index: usize,
+    path: Vec<usize>,
+    draft: Option<&'a NumberDraft>,
+) -> Element<'a, Message> {
     match &param.value {
         Value::Bool(active) => boolean_toggle(*active, |active| {
             Message::ChangeParam(index, Value::Bool(active))
@@ -108,32 +147,63 @@ pub fn field(param: &Param, index: usize) -> Element<'_, Message> {
             .on_input(move |value| Message::ChangeParam(index, Value::Text(value)))
             .style(input_style)
             .into(),
-        // TODO: Use a number input once iced's `Component` rework is finished
-        Value::I32(number) => text_input(&param.name, &number.to_string())
-            .on_input(move |value| {
-                if let Ok(num) = value.parse::<i32>() {
-                    Message::ChangeParam(index, Value::I32(num))
-                } else {
-                    Message::Noop
-                }
-            })
-            .style(input_style)
-            .into(),
+        Value::I32(number) => {
+            let number = *number;
+            let input = match draft.filter(|draft| draft.path == path) {
+                Some(draft) => draft.input.clone(),
+                None => number.to_string(),
+            };
+            number_input(
+                &input,
+                input.trim().parse::<i32>().is_ok(),
+                None,
+                move |input| match input.trim().parse() {
+                    Ok(number) => Message::ChangeParam(index, Value::I32(number)),
+                    Err(_) => Message::DraftNumber(NumberDraft {
+                        path: path.clone(),
+                        input,
+                    }),
+                },
+                move |steps| Message::ChangeParam(index, Value::I32(number.saturating_add(steps))),
+            )
+        }
+        Value::Float(float) => {
+            let (input, stepped) = (float.clone(), float.clone());
+            number_input(
+                &float.input,
+                float.is_valid(),
+                float.unit.as_deref(),
+                move |value| Message::ChangeParam(index, Value::Float(input.with_input(value))),
+                move |steps| Message::ChangeParam(index, Value::Float(stepped.stepped(steps))),
+            )
+        }
+        Value::Select(selected_index, options) if param.segmented => {
+            segmented_select(index, *selected_index, options, &param.option_descriptions)
+        }
         Value::Select(selected_index, options) => {
             let options_clone = options.clone();
             let selected = options.get(*selected_index).cloned();
-            pick_list(selected, options.clone(), String::to_string)
-                .on_select(move |selected_value| {
-                    let new_index = options_clone
-                        .iter()
-                        .position(|o| *o == selected_value)
-                        .unwrap_or(0);
-                    Message::ChangeParam(index, Value::Select(new_index, options_clone.clone()))
-                })
-                .style(crate::style::pick_list::default)
-                .menu_style(crate::style::pick_list::menu)
-                .text_size(14)
-                .into()
+            let description = param
+                .option_descriptions
+                .get(*selected_index)
+                .and_then(Option::as_deref);
+            column![
+                pick_list(selected, options.clone(), String::to_string)
+                    .on_select(move |selected_value| {
+                        let new_index = options_clone
+                            .iter()
+                            .position(|o| *o == selected_value)
+                            .unwrap_or(0);
+                        Message::ChangeParam(index, Value::Select(new_index, options_clone.clone()))
+                    })
+                    .style(crate::style::pick_list::default)
+                    .menu_style(crate::style::pick_list::menu)
+                    .text_size(14),
+                description
+                    .map(|description| { text(description).size(12).style(style::text::muted) }),
+            ]
+            .spacing(4)
+            .into()
         }
         Value::Slider(value, range) => row![
             slider(range.clone(), *value, move |v| {
@@ -145,17 +215,39 @@ pub fn field(param: &Param, index: usize) -> Element<'_, Message> {
         .spacing(8)
         .into(),
         Value::Color(color) => color_picker(index, *color),
+        Value::Length(length, pixels) => length_editor(index, *length, pixels),
+        Value::Padding(sides) => padding_editor(index, sides),
+        Value::Alignment(alignment) => {
+            let alignment = *alignment;
+            segments(row(appearance::ALIGNMENTS.map(
+                |option| -> Element<'_, Message> {
+                    segment(
+                        appearance::alignment_label(&option),
+                        option == alignment,
+                        Message::ChangeParam(index, Value::Alignment(option)),
+                    )
+                    .into()
+                },
+            )))
+        }
+        Value::Font(font) => font_editor(index, *font),
+        Value::Theme(theme) => pick_list(Some(theme.clone()), Theme::ALL, Theme::to_string)
+            .on_select(move |theme| Message::ChangeParam(index, Value::Theme(theme)))
+            .style(crate::style::pick_list::default)
+            .menu_style(crate::style::pick_list::menu)
+            .text_size(14)
+            .into(),
+        Value::Optional(enabled, param) => optional_editor(index, *enabled, param, path, draft),
+        Value::List(items, item) => list_editor(index, items, item, path, draft),
     }
 }
 
 /// Displays a label above the given `element`.
 pub fn labeled<'a>(
-    label: &'a str,
+    label: impl Into<Element<'a, Message>>,
     element: impl Into<Element<'a, Message>>,
 ) -> Element<'a, Message> {
-    column![text(label).size(14), element.into()]
-        .spacing(2)
-        .into()
+    column![label.into(), element.into()].spacing(2).into()
 }
 
 fn input_style(theme: &Theme, status: text_input::Status) -> text_input::Style {
@@ -166,57 +258,386 @@ fn input_style(theme: &Theme, status: text_input::Status) -> text_input::Style {
     }
 }
 
+/// A text input for numbers with a handle that changes them while it's dragged
+/// and buttons to decrement and increment them.
+///
+/// The `value` is shown with a danger border if it isn't `valid`,
+/// and `on_step` is called with the number of steps to change the number by.
+fn number_input<'a>(
+    value: &str,
+    valid: bool,
+    unit: Option<&'a str>,
+    on_input: impl Fn(String) -> Message + 'a,
+    on_step: impl Fn(i32) -> Message + 'a,
+) -> Element<'a, Message> {
+    let step_button = |label: &'static str, steps: i32| {
+        button(text(label).size(14).width(16).center())
+            .on_press(on_step(steps))
+            .padding([4, 6])
+            .style(|theme: &Theme, status| button::Style {
+                border: border::rounded(4),
+                ..button::text(theme, status)
+            })
+    };
+
+    let handle =
+        crate::icon::move_horizontal()
+            .width(14)
+            .height(14)
+            .style(|theme: &Theme, _status| svg::Style {
+                color: Some(theme.seed().text.scale_alpha(0.75)),
+            });
+
+    let (decrement, increment) = (step_button("-", -1), step_button("+", 1));
+
+    row![
+        crate::widget::scrub(handle, on_step),
+        decrement,
+        text_input("", value)
+            .on_input(on_input)
+            .style(move |theme, status| style::text_input::validated(theme, status, valid))
+            .width(Length::Fill),
+        increment,
+        unit.map(|unit| text(unit).size(14)),
+    ]
+    .spacing(4)
+    .align_y(Center)
+    .into()
+}
+
 /// A custom toggle for Booleans that shows true/false labels.
 /// Similar to a segmented button but only for two states.
 fn boolean_toggle<'a, Message: Clone + 'a>(
     active: bool,
     message: impl Fn(bool) -> Message,
 ) -> Element<'a, Message> {
-    let button_style = |theme: &Theme, status: button::Status, active: bool| {
-        let active_pair = if theme.palette().is_dark {
-            theme.palette().background.strongest
-        } else {
-            theme.palette().background.weakest
-        };
-        button::Style {
-            background: active.then(|| active_pair.color.into()),
-            border: border::rounded(8),
-            text_color: if active {
-                active_pair.text
-            } else {
-                theme.seed().text
-            },
-            ..button::text(theme, status)
+    // Fixed with to ensure the true/false buttons are consistent
+    const BUTTON_WIDTH: f32 = 40.0;
+    segments(row![
+        button(text("False").size(14).width(BUTTON_WIDTH).center())
+            .on_press(message(false))
+            .padding([4, 6])
+            .style(move |theme, status| segment_style(theme, status, !active)),
+        button(text("True").size(14).width(BUTTON_WIDTH).center())
+            .on_press(message(true))
+            .padding([4, 6])
+            .style(move |theme, status| segment_style(theme, status, active)),
+    ])
+}
+
+/// Segmented buttons for choosing one of the `options` of a select parameter,
+/// with the descriptions of the options as tooltips.
+fn segmented_select<'a>(
+    index: usize,
+    selected: usize,
+    options: &'a [String],
+    descriptions: &'a [Option<String>],
+) -> Element<'a, Message> {
+    let buttons = options.iter().enumerate().map(|(option, label)| {
+        let segment = segment(
+            label.clone(),
+            option == selected,
+            Message::ChangeParam(index, Value::Select(option, options.to_vec())),
+        );
+
+        match descriptions.get(option).and_then(Option::as_deref) {
+            Some(description) => tooltip(
+                segment,
+                container(text(description).size(14))
+                    .padding(6)
+                    .width(Fit.max(320)),
+                tooltip::Position::Top,
+            )
+            .style(crate::style::container::tooltip_background)
+            .into(),
+            None => segment.into(),
         }
+    });
+
+    segments(row(buttons))
+}
+
+/// A segmented button with a `label`, which stands out when it's `active`.
+fn segment<'a>(label: String, active: bool, message: Message) -> Button<'a, Message> {
+    button(text(label).size(14))
+        .on_press(message)
+        .padding([4, 8])
+        .style(move |theme, status| segment_style(theme, status, active))
+}
+
+/// Segmented buttons for the kind of a `length`, along with a number input
+/// for the portion of a `FillPortion` or the `pixels` of a `Fixed` length.
+fn length_editor<'a>(index: usize, length: Length, pixels: &'a Float) -> Element<'a, Message> {
+    let change = move |length, pixels: &Float| {
+        Message::ChangeParam(index, Value::Length(length, pixels.clone()))
+    };
+    let portion = if let Length::FillPortion(portion) = length {
+        Some(portion)
+    } else {
+        None
     };
+    let is_fixed = matches!(length, Length::Fixed(_));
+
+    // Switching to a kind with an amount starts from a sensible default.
+    let kinds = [
+        ("Fill", Length::Fill, length == Length::Fill),
+        ("Shrink", Length::Shrink, length == Length::Shrink),
+        (
+            "Portion",
+            Length::FillPortion(portion.unwrap_or(1)),
+            portion.is_some(),
+        ),
+        ("Fixed", Length::Fixed(pixels.value as f32), is_fixed),
+    ]
+    .map(|(label, kind, active)| -> Element<'a, Message> {
+        segment(String::from(label), active, change(kind, pixels)).into()
+    });
+
+    let amount = if let Some(portion) = portion {
+        Some(number_input(
+            &portion.to_string(),
+            true,
+            None,
+            move |value| match value.parse::<u16>() {
+                Ok(portion) if portion > 0 => change(Length::FillPortion(portion), pixels),
+                _ => Message::Noop,
+            },
+            move |steps| {
+                let portion = (i32::from(portion) + steps).clamp(1, i32::from(u16::MAX));
+                change(Length::FillPortion(portion as u16), pixels)
+            },
+        ))
+    } else if is_fixed {
+        Some(number_input(
+            &pixels.input,
+            pixels.is_valid(),
+            pixels.unit.as_deref(),
+            move |value| {
+                let pixels = pixels.with_input(value);
+                change(Length::Fixed(pixels.value as f32), &pixels)
+            },
+            move |steps| {
+                let pixels = pixels.stepped(steps);
+                change(Length::Fixed(pixels.value as f32), &pixels)
+            },
+        ))
+    } else {
+        None
+    };
+
+    column![segments(row(kinds)), amount].spacing(4).into()
+}
+
+/// Inputs for the pixels of each of the `sides` of a padding, going clockwise from the top.
+fn padding_editor<'a>(index: usize, sides: &'a [Float; 4]) -> Element<'a, Message> {
+    let side = move |label: &'static str, side: usize| {
+        let size = &sides[side];
+        row![
+            text(label).size(12).style(style::text::muted),
+            text_input("0", &size.input)
+                .on_input(move |value| {
+                    let mut sides = Box::new(sides.clone());
+                    sides[side] = sides[side].with_input(value);
+                    Message::ChangeParam(index, Value::Padding(sides))
+                })
+                .style(move |theme, status| {
+                    style::text_input::validated(theme, status, size.is_valid())
+                })
+                .size(12)
+                .width(Length::Fill),
+        ]
+        .spacing(4)
+        .align_y(Center)
+    };
+
+    row![
+        side("Top", 0),
+        side("Right", 1),
+        side("Bottom", 2),
+        side("Left", 3),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// A checkbox that enables an optional parameter next to the field of its inner `param`,
+/// which enables the parameter when it's edited.
+fn optional_editor<'a>(
+    index: usize,
+    enabled: bool,
+    param: &'a Param,
+    path: Vec<usize>,
+    draft: Option<&'a NumberDraft>,
+) -> Element<'a, Message> {
+    let (toggled, edited) = (param.clone(), param.clone());
+
+    let path = [path.as_slice(), &[0]].concat();
+    let editor = field(param, index, path, draft).map(move |message| match message {
+        Message::ChangeParam(_, value) => Message::ChangeParam(
+            index,
+            Value::Optional(true, Box::new(edited.with_value(value))),
+        ),
+        message => message,
+    });
+
+    row![
+        checkbox(enabled).on_toggle(move |enabled| {
+            Message::ChangeParam(index, Value::Optional(enabled, Box::new(toggled.clone())))
+        }),
+        container(editor).width(Length::Fill),
+    ]
+    .spacing(8)
+    .align_y(Center)
+    .into()
+}
+
+/// The fields of the `items` of a list parameter with buttons to reorder and remove them,
+/// followed by a button that adds a copy of the `item` parameter.
+fn list_editor<'a>(
+    index: usize,
+    items: &'a [Param],
+    item: &'a Param,
+    path: Vec<usize>,
+    draft: Option<&'a NumberDraft>,
+) -> Element<'a, Message> {
+    let change = move |items: Vec<Param>| {
+        Message::ChangeParam(index, Value::List(items, Box::new(item.clone())))
+    };
+
+    let rows = items
+        .iter()
+        .enumerate()
+        .map(|(position, param)| -> Element<'a, Message> {
+            let edited = items.to_vec();
+            let path = [path.as_slice(), &[position]].concat();
+            let editor = field(param, index, path, draft).map(move |message| match message {
+                Message::ChangeParam(_, value) => {
+                    let mut items = edited.clone();
+                    items[position] = items[position].with_value(value);
+                    change(items)
+                }
+                message => message,
+            });
+
+            let moved = |to: usize| {
+                (to < items.len()).then(|| {
+                    let mut items = items.to_vec();
+                    items.swap(position, to);
+                    change(items)
+                })
+            };
+            let mut removed = items.to_vec();
+            removed.remove(position);
 
-    // Fixed with to ensure the true/false buttons are consistent
-    const BUTTON_WIDTH: f32 = 40.0;
-    container(
-        container(
             row![
-                button(text("False").size(14).width(BUTTON_WIDTH).center())
-                    .on_press(message(false))
-                    .padding([4, 6])
-                    .style(move |theme, status| button_style(theme, status, !active)),
-                button(text("True").size(14).width(BUTTON_WIDTH).center())
-                    .on_press(message(true))
-                    .padding([4, 6])
-                    .style(move |theme, status| button_style(theme, status, active)),
+                container(editor).width(Length::Fill),
+                icon_button(
+                    crate::icon::chevron_up(),
+                    position.checked_sub(1).and_then(moved)
+                ),
+                icon_button(crate::icon::chevron_down(), moved(position + 1)),
+                icon_button(crate::icon::trash(), Some(change(removed))),
             ]
-            .width(Shrink)
-            .spacing(0),
+            .spacing(4)
+            .align_y(Center)
+            .into()
+        });
+
+    let mut added = items.to_vec();
+    added.push(item.clone());
+    let add_button = button(text("Add item").size(14))
+        .on_press(change(added))
+        .padding([4, 8])
+        .style(|theme: &Theme, status| button::Style {
+            border: border::rounded(4),
+            ..button::text(theme, status)
+        });
+
+    column(rows).push(add_button).spacing(4).into()
+}
+
+/// A small button with an `icon`, which is disabled if there's no `message`.
+fn icon_button<'a>(icon: Svg<'a>, message: Option<Message>) -> Element<'a, Message> {
+    button(icon.width(14).height(14).style(crate::style::svg::text))
+        .on_press_maybe(message)
+        .padding(4)
+        .style(|theme: &Theme, status| button::Style {
+            border: border::rounded(4),
+            ..button::text(theme, status)
+        })
+        .into()
+}
+
+/// Dropdowns for the family, weight, and style of a `font`.
+fn font_editor<'a>(index: usize, font: Font) -> Element<'a, Message> {
+    let change = move |font| Message::ChangeParam(index, Value::Font(font));
+
+    // A named family can't be chosen again once it's changed, so it's only listed while selected.
+    let families: Vec<Family> = matches!(font.family, Family::Name(_))
+        .then_some(font.family)
+        .into_iter()
+        .chain(appearance::FAMILIES)
+        .collect();
+
+    row![
+        pick_list(Some(font.family), families, appearance::family_label)
+            .on_select(move |family| change(Font { family, ..font }))
+            .style(crate::style::pick_list::default)
+            .menu_style(crate::style::pick_list::menu)
+            .text_size(14),
+        pick_list(
+            Some(font.weight),
+            appearance::WEIGHTS,
+            appearance::weight_label
         )
-        .padding(2),
-    )
-    .style(|theme: &Theme| container::Style {
-        background: Some(theme.palette().background.weak.color.into()),
-        border: border::rounded(10),
-        ..Default::default()
-    })
+        .on_select(move |weight| change(Font { weight, ..font }))
+        .style(crate::style::pick_list::default)
+        .menu_style(crate::style::pick_list::menu)
+        .text_size(14),
+        pick_list(
+            Some(font.style),
+            appearance::STYLES,
+            appearance::style_label
+        )
+        .on_select(move |style| change(Font { style, ..font }))
+        .style(crate::style::pick_list::default)
+        .menu_style(crate::style::pick_list::menu)
+        .text_size(14),
+    ]
+    .spacing(4)
     .into()
 }
 
+/// Places segmented buttons next to each other on a shared background.
+fn segments<'a, Message: 'a>(buttons: Row<'a, Message>) -> Element<'a, Message> {
+    container(container(buttons.width(Shrink).spacing(0)).padding(2))
+        .style(|theme: &Theme| container::Style {
+            background: Some(theme.palette().background.weak.color.into()),
+            border: border::rounded(10),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// The style of a segmented button, which stands out when it's `active`.
+fn segment_style(theme: &Theme, status: button::Status, active: bool) -> button::Style {
+    let active_pair = if theme.palette().is_dark {
+        theme.palette().background.strongest
+    } else {
+        theme.palette().background.weakest
+    };
+    button::Style {
+        background: active.then(|| active_pair.color.into()),
+        border: border::rounded(8),
+        text_color: if active {
+            active_pair.text
+        } else {
+            theme.seed().text
+        },
+        ..button::text(theme, status)
+    }
+}
+
 /// A simple color picker with a preview swatch.
 fn color_picker<'a>(index: usize, color: Color) -> Element<'a, Message> {
     use iced::{border, widget::container};
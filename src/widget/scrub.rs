@@ -0,0 +1,216 @@
+//! A handle that changes a number by whole steps while the user drags it horizontally.
+
+use iced::{
+    Element, Event, Length, Rectangle, Size, Theme, Vector,
+    advanced::{
+        self, Layout, Shell, Widget, layout, mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+};
+
+/// The distance the cursor has to move horizontally to change the number by one step.
+const PIXELS_PER_STEP: f32 = 8.0;
+
+/// A widget that emits the number of steps its `content` was dragged by.
+pub struct Scrub<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_scrub: Box<dyn Fn(i32) -> Message + 'a>,
+}
+
+impl<'a, Message, Renderer> Scrub<'a, Message, Renderer> {
+    /// Creates a new [`Scrub`] that emits the message returned by `on_scrub`
+    /// with the number of steps, which are negative when dragging to the left.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_scrub: impl Fn(i32) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_scrub: Box::new(on_scrub),
+        }
+    }
+}
+
+/// Creates a new [`Scrub`] that emits the message returned by `on_scrub`
+/// with the number of steps the `content` was dragged by.
+pub fn scrub<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_scrub: impl Fn(i32) -> Message + 'a,
+) -> Scrub<'a, Message, Renderer> {
+    Scrub::new(content, on_scrub)
+}
+
+/// The horizontal cursor position where the last step was emitted while dragging.
+#[derive(Default)]
+struct State {
+    drag: Option<f32>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Theme, Renderer> for Scrub<'a, Message, Renderer>
+where
+    Renderer: advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Scrubbing takes precedence over the content until the drag is released.
+        if let Some(start) = state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    let steps = ((position.x - start) / PIXELS_PER_STEP).trunc();
+                    if steps != 0.0 {
+                        state.drag = Some(start + steps * PIXELS_PER_STEP);
+                        shell.publish((self.on_scrub)(steps as i32));
+                    }
+                    shell.capture_event();
+                    return;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    state.drag = None;
+                    shell.capture_event();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(position) = cursor.position_over(layout.bounds())
+        {
+            let state = tree.state.downcast_mut::<State>();
+            state.drag = Some(position.x);
+            shell.capture_event();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.drag.is_some() || cursor.is_over(layout.bounds()) {
+            mouse::Interaction::ResizingHorizontally
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Scrub<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: advanced::Renderer + 'a,
+{
+    fn from(scrub: Scrub<'a, Message, Renderer>) -> Self {
+        Self::new(scrub)
+    }
+}
@@ -0,0 +1,557 @@
+//! A stage that draws a background behind its content and lets the user zoom and pan it.
+//!
+//! The content is laid out within the stage's bounds divided by the zoom factor and then
+//! scaled up or down while drawing, so it always covers the stage when zoomed in or out.
+//! When zoomed to fit, the factor is computed from the natural size of the content.
+
+use iced::{
+    Color, Element, Event, Length, Point, Rectangle, Size, Theme, Transformation, Vector,
+    advanced::{
+        self, Layout, Shell, Widget, layout, mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+};
+
+use crate::canvas::{self, Background, Settings};
+
+/// The size of a single square in the checkerboard background.
+const CHECKER_SIZE: f32 = 10.0;
+
+/// A widget that draws a background behind its content and zooms and pans it.
+pub struct Stage<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    settings: Settings,
+    on_pan: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    on_fit: Option<(Option<f32>, Box<dyn Fn(f32) -> Message + 'a>)>,
+}
+
+impl<'a, Message, Renderer> Stage<'a, Message, Renderer> {
+    /// Creates a new [`Stage`] that renders the `content` with the given canvas `settings`.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        settings: Settings,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            settings,
+            on_pan: None,
+            on_fit: None,
+        }
+    }
+
+    /// Sets the function that emits the new pan offset while the user drags the stage.
+    pub fn on_pan(mut self, on_pan: impl Fn(Vector) -> Message + 'a) -> Self {
+        self.on_pan = Some(Box::new(on_pan));
+        self
+    }
+
+    /// Sets the function that emits the scale the content was shrunk by to fit the stage.
+    ///
+    /// It's only emitted when zoomed to fit and the scale differs from the known `fit_scale`.
+    pub fn on_fit(mut self, fit_scale: Option<f32>, on_fit: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_fit = Some((fit_scale, Box::new(on_fit)));
+        self
+    }
+
+    /// The factor the content is scaled by with the fit scale stored in the `state`.
+    fn scale(&self, state: &State) -> f32 {
+        self.settings.zoom.scale(state.fit_scale)
+    }
+
+    /// The transformation from the content's layout to where it is drawn on the screen.
+    fn transformation(&self, bounds: Rectangle, scale: f32) -> Transformation {
+        let offset = self.settings.offset;
+        Transformation::translate(bounds.x + offset.x, bounds.y + offset.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-bounds.x, -bounds.y)
+    }
+
+    /// Maps a `point` on the screen to the coordinates of the content's layout.
+    fn to_content(&self, point: Point, bounds: Rectangle, scale: f32) -> Point {
+        let offset = self.settings.offset;
+        Point::new(
+            bounds.x + (point.x - bounds.x - offset.x) / scale,
+            bounds.y + (point.y - bounds.y - offset.y) / scale,
+        )
+    }
+
+    /// The cursor in the coordinates of the content's layout.
+    fn content_cursor(
+        &self,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        scale: f32,
+    ) -> mouse::Cursor {
+        match cursor.position_over(bounds) {
+            Some(position) => mouse::Cursor::Available(self.to_content(position, bounds, scale)),
+            None => mouse::Cursor::Unavailable,
+        }
+    }
+
+    /// The visible area of the stage in the coordinates of the content's layout.
+    fn content_viewport(&self, bounds: Rectangle, scale: f32) -> Rectangle {
+        Rectangle::new(
+            self.to_content(bounds.position(), bounds, scale),
+            Size::new(bounds.width / scale, bounds.height / scale),
+        )
+    }
+}
+
+/// Creates a new [`Stage`] that renders the `content` with the given canvas `settings`.
+pub fn stage<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    settings: Settings,
+) -> Stage<'a, Message, Renderer> {
+    Stage::new(content, settings)
+}
+
+/// The state of a [`Stage`].
+struct State {
+    /// The cursor position and pan offset when the user started dragging the stage.
+    drag: Option<(Point, Vector)>,
+    /// The scale that makes the content fit the stage, computed during layout.
+    fit_scale: f32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            drag: None,
+            fit_scale: 1.0,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Theme, Renderer> for Stage<'a, Message, Renderer>
+where
+    Renderer: advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.resolve(Length::Fill, Length::Fill, Size::ZERO);
+        let state = tree.state.downcast_mut::<State>();
+        if self.settings.zoom == canvas::Zoom::Fit {
+            // Lay out the content within the stage's width first to find its natural height,
+            // so text still wraps and only content that can't shrink is scaled down.
+            let natural = self.content.as_widget_mut().layout(
+                &mut tree.children[0],
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(size.width, f32::INFINITY)),
+            );
+            state.fit_scale = canvas::fit_scale(natural.size(), size);
+        }
+
+        let scale = self.scale(state);
+        let available = Size::new(size.width / scale, size.height / scale);
+
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, available),
+        );
+
+        // Center the content within the area it was laid out in.
+        let position = Point::new(
+            (available.width - content.size().width) / 2.0,
+            (available.height - content.size().height) / 2.0,
+        );
+
+        layout::Node::with_children(size, vec![content.move_to(position)])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+        let scale = self.scale(state);
+
+        if self.settings.zoom == canvas::Zoom::Fit
+            && let Some((known, on_fit)) = &self.on_fit
+            && *known != Some(state.fit_scale)
+        {
+            shell.publish(on_fit(state.fit_scale));
+        }
+
+        // Panning takes precedence over the content until the drag is released.
+        if let Some((start, offset)) = state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(on_pan) = &self.on_pan {
+                        shell.publish(on_pan(offset + (*position - start)));
+                    }
+                    shell.capture_event();
+                    return;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left | mouse::Button::Middle,
+                )) => {
+                    state.drag = None;
+                    shell.capture_event();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let content_event = match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: self.to_content(*position, bounds, scale),
+                })
+            }
+            _ => event.clone(),
+        };
+
+        let content_cursor = self.content_cursor(cursor, bounds, scale);
+        let content_viewport = self.content_viewport(bounds, scale);
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            &content_event,
+            layout.children().next().expect("Stage must have content"),
+            content_cursor,
+            renderer,
+            shell,
+            &content_viewport,
+        );
+
+        if shell.is_event_captured() || self.on_pan.is_none() {
+            return;
+        }
+
+        // Start panning when dragging an area of the stage the content didn't handle.
+        if let Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left | mouse::Button::Middle,
+        )) = event
+            && let Some(position) = cursor.position_over(bounds)
+        {
+            let state = tree.state.downcast_mut::<State>();
+            state.drag = Some((position, self.settings.offset));
+            shell.capture_event();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().expect("Stage must have content");
+        let scale = self.scale(tree.state.downcast_ref::<State>());
+
+        renderer.with_layer(bounds, |renderer| {
+            draw_background(renderer, theme, self.settings.background, bounds);
+
+            renderer.with_transformation(self.transformation(bounds, scale), |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    self.content_cursor(cursor, bounds, scale),
+                    &self.content_viewport(bounds, scale),
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let scale = self.scale(state);
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().expect("Stage must have content"),
+            self.content_cursor(cursor, bounds, scale),
+            &self.content_viewport(bounds, scale),
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::None
+            && self.on_pan.is_some()
+            && cursor.is_over(bounds)
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().expect("Stage must have content"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let bounds = layout.bounds();
+        let scale = self.scale(tree.state.downcast_ref::<State>());
+        let content_viewport = self.content_viewport(bounds, scale);
+
+        // Overlays are laid out like the content and then panned and scaled along with it.
+        let translation = translation + Vector::new(bounds.x, bounds.y) + self.settings.offset
+            - Vector::new(bounds.x, bounds.y) * scale;
+
+        self.content
+            .as_widget_mut()
+            .overlay(
+                &mut tree.children[0],
+                layout.children().next().expect("Stage must have content"),
+                renderer,
+                &content_viewport,
+                Vector::ZERO,
+            )
+            .map(|raw| {
+                overlay::Element::new(Box::new(Overlay {
+                    raw,
+                    translation,
+                    scale,
+                }))
+            })
+    }
+}
+
+impl<'a, Message, Renderer> From<Stage<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: advanced::Renderer + 'a,
+{
+    fn from(stage: Stage<'a, Message, Renderer>) -> Self {
+        Self::new(stage)
+    }
+}
+
+/// An overlay of the stage's content that is panned and scaled along with it.
+struct Overlay<'a, Message, Renderer> {
+    raw: overlay::Element<'a, Message, Theme, Renderer>,
+    /// Where the origin of the overlay's layout is drawn on the screen.
+    translation: Vector,
+    scale: f32,
+}
+
+impl<Message, Renderer> Overlay<'_, Message, Renderer> {
+    /// The transformation from the overlay's layout to where it is drawn on the screen.
+    fn transformation(&self) -> Transformation {
+        Transformation::translate(self.translation.x, self.translation.y)
+            * Transformation::scale(self.scale)
+    }
+
+    /// Maps a `point` on the screen to the coordinates of the overlay's layout.
+    fn to_content(&self, point: Point) -> Point {
+        Point::new(
+            (point.x - self.translation.x) / self.scale,
+            (point.y - self.translation.y) / self.scale,
+        )
+    }
+
+    /// The cursor in the coordinates of the overlay's layout.
+    fn content_cursor(&self, cursor: mouse::Cursor) -> mouse::Cursor {
+        match cursor.position() {
+            Some(position) => mouse::Cursor::Available(self.to_content(position)),
+            None => mouse::Cursor::Unavailable,
+        }
+    }
+}
+
+impl<Message, Renderer> advanced::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, Message, Renderer>
+where
+    Renderer: advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.raw.as_overlay_mut().layout(
+            renderer,
+            Size::new(bounds.width / self.scale, bounds.height / self.scale),
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        renderer.with_transformation(self.transformation(), |renderer| {
+            self.raw
+                .as_overlay()
+                .draw(renderer, theme, style, layout, self.content_cursor(cursor));
+        });
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.raw
+            .as_overlay_mut()
+            .operate(layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let event = match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: self.to_content(*position),
+                })
+            }
+            _ => event.clone(),
+        };
+
+        let cursor = self.content_cursor(cursor);
+        self.raw
+            .as_overlay_mut()
+            .update(&event, layout, cursor, renderer, shell);
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.raw
+            .as_overlay()
+            .mouse_interaction(layout, self.content_cursor(cursor), renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (translation, scale) = (self.translation, self.scale);
+        self.raw
+            .as_overlay_mut()
+            .overlay(layout, renderer)
+            .map(|raw| {
+                overlay::Element::new(Box::new(Overlay {
+                    raw,
+                    translation,
+                    scale,
+                }))
+            })
+    }
+
+    fn index(&self) -> f32 {
+        self.raw.as_overlay().index()
+    }
+}
+
+/// Draws the `background` of the stage within its `bounds`.
+fn draw_background<Renderer: advanced::Renderer>(
+    renderer: &mut Renderer,
+    theme: &Theme,
+    background: Background,
+    bounds: Rectangle,
+) {
+    let fill = |renderer: &mut Renderer, bounds: Rectangle, color: Color| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    };
+
+    match background {
+        Background::Theme => fill(renderer, bounds, theme.palette().background.base.color),
+        Background::Custom(color) => fill(renderer, bounds, color),
+        Background::Checkerboard => {
+            fill(renderer, bounds, Color::WHITE);
+
+            let columns = (bounds.width / CHECKER_SIZE).ceil() as usize;
+            let rows = (bounds.height / CHECKER_SIZE).ceil() as usize;
+            let dark = Color::from_rgb8(204, 204, 204);
+            for row in 0..rows {
+                // Every other square is dark, alternating the first square on each row.
+                for column in (row % 2..columns).step_by(2) {
+                    let square = Rectangle::new(
+                        Point::new(
+                            bounds.x + column as f32 * CHECKER_SIZE,
+                            bounds.y + row as f32 * CHECKER_SIZE,
+                        ),
+                        Size::new(CHECKER_SIZE, CHECKER_SIZE),
+                    );
+                    fill(
+                        renderer,
+                        square.intersection(&bounds).unwrap_or(square),
+                        dark,
+                    );
+                }
+            }
+        }
+    }
+}
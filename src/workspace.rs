@@ -0,0 +1,386 @@
+//! The state of the workspace that is saved between launches of the preview app.
+
+use std::{collections::BTreeMap, path::Path};
+
+use iced::{Color, Padding, Vector};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    canvas::{self, Background, Zoom},
+    dynamic::{self, Param, Value, appearance},
+};
+
+/// The default location of the state file, relative to the working directory.
+pub const DEFAULT_STATE_FILE: &str = "target/snowscape/state.ron";
+
+/// The UI state that is restored the next time the preview app is launched.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Workspace {
+    /// The path of the selected preview, including its group.
+    pub selected: Option<String>,
+    /// The width of the sidebar.
+    pub sidebar_width: Option<f32>,
+    /// The height of the configuration pane underneath the preview.
+    pub config_pane_height: Option<f32>,
+    /// The name of the selected configuration tab.
+    pub config_tab: Option<String>,
+    /// The name of the selected theme, or `None` to follow the system theme.
+    pub theme: Option<String>,
+    /// The search query that filters previews.
+    pub search: String,
+    /// The dynamic parameter values of each preview keyed by its path,
+    /// and then by the name of the parameter.
+    pub params: BTreeMap<String, BTreeMap<String, SavedValue>>,
+    /// The canvas settings of each preview that were changed, keyed by its path.
+    pub canvas: BTreeMap<String, SavedCanvas>,
+}
+
+impl Workspace {
+    /// Loads the workspace from the state file at `path`.
+    ///
+    /// Returns `None` if the file doesn't exist or can't be read.
+    pub fn load(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        match ron::from_str(&content) {
+            Ok(workspace) => Some(workspace),
+            Err(e) => {
+                eprintln!("Failed to read state file '{}': {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Saves the workspace to the state file at `path`, creating its parent directories.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(std::io::Error::other)?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)
+    }
+}
+
+/// A dynamic parameter value as it's saved in the state file.
+///
+/// Only the value the user chose is saved, so the options of a select parameter
+/// or the range of a slider always come from the preview itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SavedValue {
+    Bool(bool),
+    Text(String),
+    I32(i32),
+    Float(f64),
+    /// The name of the selected option.
+    Select(String),
+    Slider(f32),
+    /// The color as `[r, g, b, a]`.
+    Color([f32; 4]),
+    /// The length in the format of [`Value::parse`], e.g. `portion 2`.
+    Length(String),
+    /// The padding as `[top, right, bottom, left]`.
+    Padding([f32; 4]),
+    /// The name of the alignment.
+    Alignment(String),
+    /// The font in the format of [`Value::parse`], e.g. `monospace bold`.
+    Font(String),
+    /// The name of the theme.
+    Theme(String),
+    /// The saved value of an optional parameter, or `None` if it's disabled.
+    Optional(Option<Box<SavedValue>>),
+    /// The saved values of the items of a list.
+    List(Vec<SavedValue>),
+}
+
+impl SavedValue {
+    /// Saves the current `value` of a parameter.
+    pub fn new(value: &Value) -> Option<Self> {
+        Some(match value {
+            Value::Bool(value) => SavedValue::Bool(*value),
+            Value::Text(value) => SavedValue::Text(value.clone()),
+            Value::I32(value) => SavedValue::I32(*value),
+            Value::Float(float) => SavedValue::Float(float.value),
+            Value::Select(index, options) => SavedValue::Select(options.get(*index)?.clone()),
+            Value::Slider(value, _) => SavedValue::Slider(*value),
+            Value::Color(color) => SavedValue::Color([color.r, color.g, color.b, color.a]),
+            Value::Length(length, _) => SavedValue::Length(appearance::format_length(*length)),
+            Value::Padding(sides) => {
+                SavedValue::Padding(sides.each_ref().map(|side| side.value as f32))
+            }
+            Value::Alignment(alignment) => {
+                SavedValue::Alignment(appearance::alignment_label(alignment))
+            }
+            Value::Font(font) => SavedValue::Font(appearance::format_font(*font)),
+            Value::Theme(theme) => SavedValue::Theme(theme.to_string()),
+            Value::Optional(true, param) => {
+                SavedValue::Optional(Some(Box::new(SavedValue::new(&param.value)?)))
+            }
+            Value::Optional(false, _) => SavedValue::Optional(None),
+            Value::List(items, _) => SavedValue::List(
+                items
+                    .iter()
+                    .map(|item| SavedValue::new(&item.value))
+                    .collect::<Option<_>>()?,
+            ),
+        })
+    }
+
+    /// The value of the `current` parameter after restoring the saved value,
+    /// or `None` if the saved value no longer applies, e.g. because its type changed.
+    pub fn restore(&self, current: &Value) -> Option<Value> {
+        match (self, current) {
+            (SavedValue::Bool(value), Value::Bool(_)) => Some(Value::Bool(*value)),
+            (SavedValue::Text(value), Value::Text(_)) => Some(Value::Text(value.clone())),
+            (SavedValue::I32(value), Value::I32(_)) => Some(Value::I32(*value)),
+            (SavedValue::Float(value), Value::Float(float)) => {
+                Some(Value::Float(float.with_value(*value)))
+            }
+            (SavedValue::Select(selected), Value::Select(_, options)) => {
+                let index = options.iter().position(|option| option == selected)?;
+                Some(Value::Select(index, options.clone()))
+            }
+            (SavedValue::Slider(value), Value::Slider(_, range)) => Some(Value::Slider(
+                value.clamp(*range.start(), *range.end()),
+                range.clone(),
+            )),
+            (SavedValue::Color([r, g, b, a]), Value::Color(_)) => {
+                Some(Value::Color(Color::from_rgba(*r, *g, *b, *a)))
+            }
+            (SavedValue::Padding([top, right, bottom, left]), Value::Padding(_)) => {
+                Some(Value::Padding(dynamic::padding_sides(Padding {
+                    top: *top,
+                    right: *right,
+                    bottom: *bottom,
+                    left: *left,
+                })))
+            }
+            (SavedValue::Length(saved), Value::Length(..))
+            | (SavedValue::Alignment(saved), Value::Alignment(_))
+            | (SavedValue::Font(saved), Value::Font(_))
+            | (SavedValue::Theme(saved), Value::Theme(_)) => current.parse(saved),
+            (SavedValue::Optional(None), Value::Optional(_, param)) => {
+                Some(Value::Optional(false, param.clone()))
+            }
+            (SavedValue::Optional(Some(saved)), Value::Optional(_, param)) => {
+                Some(Value::Optional(
+                    true,
+                    Box::new(param.with_value(saved.restore(&param.value)?)),
+                ))
+            }
+            (SavedValue::List(saved), Value::List(_, item)) => {
+                let items = saved
+                    .iter()
+                    .map(|saved| Some(item.with_value(saved.restore(&item.value)?)))
+                    .collect::<Option<_>>()?;
+                Some(Value::List(items, item.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The canvas settings of a preview as they're saved in the state file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedCanvas {
+    /// The zoom percentage, or `None` if the preview is zoomed to fit.
+    pub zoom: Option<u16>,
+    /// The background behind the preview.
+    pub background: SavedBackground,
+    /// The pan offset as `[x, y]`.
+    pub offset: [f32; 2],
+}
+
+impl Default for SavedCanvas {
+    fn default() -> Self {
+        Self::new(&canvas::Settings::default())
+    }
+}
+
+impl SavedCanvas {
+    /// Saves the canvas `settings` of a preview.
+    pub fn new(settings: &canvas::Settings) -> Self {
+        Self {
+            zoom: match settings.zoom {
+                Zoom::Fit => None,
+                Zoom::Percent(percent) => Some(percent),
+            },
+            background: match settings.background {
+                Background::Theme => SavedBackground::Theme,
+                Background::Checkerboard => SavedBackground::Checkerboard,
+                Background::Custom(color) => {
+                    SavedBackground::Custom([color.r, color.g, color.b, color.a])
+                }
+            },
+            offset: [settings.offset.x, settings.offset.y],
+        }
+    }
+
+    /// The canvas settings after restoring them, falling back to fit if the
+    /// saved zoom is outside the supported range.
+    pub fn restore(&self) -> canvas::Settings {
+        let [x, y] = self.offset;
+        canvas::Settings {
+            zoom: self
+                .zoom
+                .filter(|percent| (Zoom::MIN..=Zoom::MAX).contains(percent))
+                .map_or(Zoom::Fit, Zoom::Percent),
+            background: match self.background {
+                SavedBackground::Theme => Background::Theme,
+                SavedBackground::Checkerboard => Background::Checkerboard,
+                SavedBackground::Custom([r, g, b, a]) => {
+                    Background::Custom(Color::from_rgba(r, g, b, a))
+                }
+            },
+            offset: Vector::new(x, y),
+        }
+    }
+}
+
+/// A canvas background as it's saved in the state file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SavedBackground {
+    Theme,
+    Checkerboard,
+    /// The color as `[r, g, b, a]`.
+    Custom([f32; 4]),
+}
+
+/// Saves the current values of the `params` keyed by their name.
+pub fn save_params(params: &[Param]) -> BTreeMap<String, SavedValue> {
+    params
+        .iter()
+        .filter_map(|param| Some((param.name.clone(), SavedValue::new(&param.value)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Workspaces are written to and read back from RON without losing any state.
+    #[test]
+    fn workspace_round_trips_through_ron() {
+        let workspace = Workspace {
+            selected: Some(String::from("Button/Disabled")),
+            sidebar_width: Some(300.0),
+            config_tab: Some(String::from("Tests")),
+            search: String::from("form"),
+            params: BTreeMap::from([(
+                String::from("Label"),
+                BTreeMap::from([(String::from("Content"), SavedValue::Text("Hi".into()))]),
+            )]),
+            ..Workspace::default()
+        };
+
+        let content = ron::to_string(&workspace).unwrap();
+        assert_eq!(ron::from_str::<Workspace>(&content).unwrap(), workspace);
+    }
+
+    /// Canvas settings are restored as they were saved, ignoring unsupported zoom levels.
+    #[test]
+    fn canvas_round_trips_through_ron() {
+        let settings = canvas::Settings {
+            zoom: Zoom::Percent(150),
+            background: Background::Custom(Color::from_rgb8(255, 0, 0)),
+            offset: Vector::new(12.0, -4.5),
+        };
+        let content = ron::to_string(&SavedCanvas::new(&settings)).unwrap();
+        let saved: SavedCanvas = ron::from_str(&content).unwrap();
+        assert_eq!(saved.restore(), settings);
+
+        let saved: SavedCanvas = ron::from_str("(zoom: Some(1000))").unwrap();
+        assert_eq!(saved.restore(), canvas::Settings::default());
+    }
+
+    /// Missing fields fall back to their defaults so older state files can still be read.
+    #[test]
+    fn workspace_defaults_missing_fields() {
+        let workspace: Workspace = ron::from_str("(search: \"card\")").unwrap();
+        assert_eq!(workspace.search, "card");
+        assert_eq!(workspace.selected, None);
+    }
+
+    /// Select parameters are restored by the name of the option rather than its index.
+    #[test]
+    fn restore_select_by_option_name() {
+        let options = vec![String::from("Small"), String::from("Large")];
+        let saved = SavedValue::new(&Value::Select(1, options.clone())).unwrap();
+
+        let reordered = vec![String::from("Large"), String::from("Small")];
+        assert_eq!(
+            saved.restore(&Value::Select(1, reordered.clone())),
+            Some(Value::Select(0, reordered))
+        );
+        assert_eq!(saved.restore(&Value::Select(0, vec![])), None);
+    }
+
+    /// Saved values are ignored if the type of the parameter has changed.
+    #[test]
+    fn restore_ignores_mismatched_types() {
+        assert_eq!(SavedValue::Bool(true).restore(&Value::I32(1)), None);
+        assert_eq!(
+            SavedValue::Slider(5.0).restore(&Value::Slider(1.0, 0.0..=10.0)),
+            Some(Value::Slider(5.0, 0.0..=10.0))
+        );
+    }
+
+    /// Sliders are clamped to their current range if it changed since they were saved.
+    #[test]
+    fn restore_slider_within_range() {
+        assert_eq!(
+            SavedValue::Slider(50.0).restore(&Value::Slider(1.0, 0.0..=10.0)),
+            Some(Value::Slider(10.0, 0.0..=10.0))
+        );
+    }
+
+    /// Lengths, paddings, fonts, and themes are restored from their saved forms.
+    #[test]
+    fn restore_appearance_values() {
+        for value in [
+            Value::Length(iced::Length::FillPortion(2), dynamic::Float::pixels(100.0)),
+            Value::Length(iced::Length::Fixed(24.5), dynamic::Float::pixels(24.5)),
+            Value::Padding(dynamic::padding_sides(Padding::new(4.0).left(12.0))),
+            Value::Alignment(iced::alignment::Horizontal::Right),
+            Value::Font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..iced::Font::MONOSPACE
+            }),
+            Value::Theme(iced::Theme::Dracula),
+        ] {
+            let saved = SavedValue::new(&value).unwrap();
+            let content = ron::to_string(&saved).unwrap();
+            let saved: SavedValue = ron::from_str(&content).unwrap();
+            assert_eq!(saved.restore(&value), Some(value));
+        }
+    }
+
+    /// Optional parameters and the items of lists are restored with their inner parameters.
+    #[test]
+    fn restore_optional_and_list() {
+        let size = Param::new("Size", Value::Slider(1.0, 0.0..=10.0));
+        let saved = SavedValue::new(&Value::List(
+            vec![size.with_value(Value::Slider(4.0, 0.0..=10.0))],
+            Box::new(size.clone()),
+        ))
+        .unwrap();
+        assert_eq!(saved, SavedValue::List(vec![SavedValue::Slider(4.0)]));
+        assert_eq!(
+            saved.restore(&Value::List(Vec::new(), Box::new(size.clone()))),
+            Some(Value::List(
+                vec![size.with_value(Value::Slider(4.0, 0.0..=10.0))],
+                Box::new(size.clone())
+            ))
+        );
+
+        let disabled = Value::Optional(false, Box::new(size.clone()));
+        assert_eq!(
+            SavedValue::new(&disabled)
+                .unwrap()
+                .restore(&Value::Optional(true, Box::new(size))),
+            Some(disabled)
+        );
+    }
+}